@@ -8,6 +8,8 @@ fn main() {
         input: &["src/client/schema.proto"],
         includes: &["src/client"],
         customize: Customize {
+            // The derives are behind `#[cfg_attr(feature = "with-serde", ...)]`.
+            serde_derive: Some(true),
             ..Default::default()
         },
     })
@@ -0,0 +1,48 @@
+//! Flies the active vessel from the launch pad to a circular orbit.
+//!
+//! With a vessel on the launch pad, run:
+//!
+//! ```text
+//! cargo run --example ascent -- [altitude] [heading]
+//! ```
+//!
+//! The altitude defaults to 80 km and the heading to 90°, due east.
+use krpc_bindings::client::Connection;
+use krpc_bindings::guidance::{Ascent, AscentPhase};
+use krpc_bindings::spacecenter::SpaceCenter;
+
+use failure::{err_msg, Error};
+
+fn main() -> Result<(), Error> {
+    let mut args = std::env::args().skip(1);
+    let altitude = match args.next() {
+        Some(altitude) => altitude.parse()?,
+        None => 80_000.0,
+    };
+    let heading = match args.next() {
+        Some(heading) => heading.parse()?,
+        None => 90.0,
+    };
+
+    let client = Connection::connect_localhost("ascent")?;
+    let space_center = SpaceCenter::new(&client);
+    let vessel = space_center
+        .active_vessel()?
+        .ok_or_else(|| err_msg("there is no active vessel"))?;
+
+    let mut ascent = Ascent::new(&client, &vessel, altitude)?.heading(heading);
+    let mut phase = ascent.phase();
+    println!("{:?}", phase);
+    loop {
+        let next = ascent.step()?;
+        if next != phase {
+            phase = next;
+            println!("{:?}", phase);
+        }
+        if phase == AscentPhase::Done {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Records a session of the server's reference frame transforms, in the format replayed by
+//! `tests/frame_transforms.rs`.
+//!
+//! With a vessel in flight, run:
+//!
+//! ```text
+//! cargo run --example record_frames > tests/data/frame_transforms.txt
+//! ```
+//!
+//! The frames are the non-rotating frame of the body the vessel orbits (`R`), the rotating
+//! frame of that body (`A`) and the frame of the vessel (`B`). All of the calls are made in a
+//! single request, so that every answer describes the same instant.
+use krpc_bindings::client::{Connection, ProcedureCall};
+use krpc_bindings::codec::Decode;
+use krpc_bindings::spacecenter::{ReferenceFrame, SpaceCenter};
+use krpc_bindings::{Quaternion, Vector3};
+
+use failure::{err_msg, Error};
+
+#[derive(Copy, Clone)]
+enum Value {
+    Vector3,
+    Quaternion,
+}
+
+/// A line of the recording: a prefix, followed by the results of the calls.
+struct Row {
+    prefix: String,
+    calls: Vec<(ProcedureCall, Value)>,
+}
+
+fn vector(v: Vector3) -> String {
+    format!("{:?} {:?} {:?}", v.x, v.y, v.z)
+}
+
+fn quaternion(q: Quaternion) -> String {
+    format!("{:?} {:?} {:?} {:?}", q.x, q.y, q.z, q.w)
+}
+
+fn main() -> Result<(), Error> {
+    let client = Connection::connect_localhost("record_frames")?;
+    let space_center = SpaceCenter::new(&client);
+    let vessel = space_center
+        .active_vessel()?
+        .ok_or_else(|| err_msg("there is no active vessel"))?;
+    let orbit = vessel.orbit()?;
+    let body = orbit.body()?;
+    let frames: Vec<(&str, ReferenceFrame)> = vec![
+        ("R", body.non_rotating_reference_frame()?),
+        ("A", body.reference_frame()?),
+        ("B", vessel.reference_frame()?),
+    ];
+    let root = &frames[0].1;
+    let calls = space_center.call();
+
+    let mut rows = Vec::new();
+    for (name, frame) in frames.iter().skip(1) {
+        let mut sample = vec![
+            (
+                calls.transform_position(Vector3::ZERO, frame, root)?,
+                Value::Vector3,
+            ),
+            (
+                calls.transform_rotation(Quaternion::IDENTITY, frame, root)?,
+                Value::Quaternion,
+            ),
+            (
+                calls.transform_velocity(Vector3::ZERO, Vector3::ZERO, frame, root)?,
+                Value::Vector3,
+            ),
+        ];
+        for &axis in [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)].iter() {
+            sample.push((
+                calls.transform_velocity(axis.into(), Vector3::ZERO, frame, root)?,
+                Value::Vector3,
+            ));
+        }
+        rows.push(Row {
+            prefix: format!("sample {}", name),
+            calls: sample,
+        });
+    }
+
+    let positions = [
+        Vector3::new(1.0, -2.0, 0.5),
+        Vector3::new(-27.5, 23.25, 17.75),
+        Vector3::new(549051.5, -578285.25, -109309.5),
+    ];
+    let directions = [Vector3::new(0.6, -0.8, 0.0), Vector3::new(-0.2, 0.3, 0.9)];
+    let rotations = [
+        Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7),
+        Quaternion::from_axis_angle(Vector3::new(-3.0, 1.0, 0.5), 2.1),
+    ];
+    let velocities = [
+        (
+            Vector3::new(5.0, -3.0, 2.0),
+            Vector3::new(120.0, -40.0, 300.0),
+        ),
+        (
+            Vector3::new(640000.0, -12000.0, 50000.0),
+            Vector3::new(2200.0, 15.0, -1800.0),
+        ),
+    ];
+
+    let pairs = [
+        ("A", "R"),
+        ("B", "R"),
+        ("R", "A"),
+        ("R", "B"),
+        ("A", "B"),
+        ("B", "A"),
+    ];
+    let frame = |name: &str| &frames.iter().find(|(n, _)| *n == name).unwrap().1;
+    for &(from_name, to_name) in pairs.iter() {
+        let (from, to) = (frame(from_name), frame(to_name));
+        let pair = format!("{} {}", from_name, to_name);
+        for &p in positions.iter() {
+            rows.push(Row {
+                prefix: format!("position {} {}", pair, vector(p)),
+                calls: vec![(calls.transform_position(p, from, to)?, Value::Vector3)],
+            });
+        }
+        for &d in directions.iter() {
+            rows.push(Row {
+                prefix: format!("direction {} {}", pair, vector(d)),
+                calls: vec![(calls.transform_direction(d, from, to)?, Value::Vector3)],
+            });
+        }
+        for &q in rotations.iter() {
+            rows.push(Row {
+                prefix: format!("rotation {} {}", pair, quaternion(q)),
+                calls: vec![(calls.transform_rotation(q, from, to)?, Value::Quaternion)],
+            });
+        }
+        for &(p, v) in velocities.iter() {
+            rows.push(Row {
+                prefix: format!("velocity {} {} {}", pair, vector(p), vector(v)),
+                calls: vec![(calls.transform_velocity(p, v, from, to)?, Value::Vector3)],
+            });
+        }
+    }
+
+    let batch = rows
+        .iter()
+        .flat_map(|row| row.calls.iter().map(|(call, _)| call.clone()))
+        .collect();
+    let mut results = client.invoke_batch(batch)?.into_iter();
+
+    println!("# Recorded by `cargo run --example record_frames`.");
+    for row in rows.iter() {
+        let mut line = row.prefix.clone();
+        for &(_, value) in row.calls.iter() {
+            let bytes = results.next().ok_or_else(|| err_msg("missing result"))??;
+            let text = match value {
+                Value::Vector3 => vector(Vector3::decode(&bytes, &client)?),
+                Value::Quaternion => quaternion(Quaternion::decode(&bytes, &client)?),
+            };
+            line.push(' ');
+            line.push_str(&text);
+        }
+        println!("{}", line);
+    }
+    Ok(())
+}
@@ -0,0 +1,394 @@
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::{braced, bracketed, parenthesized, Attribute, Expr, Ident, Token, Type};
+
+mod kw {
+    syn::custom_keyword!(service);
+    syn::custom_keyword!(object);
+    syn::custom_keyword!(properties);
+    syn::custom_keyword!(methods);
+    syn::custom_keyword!(static_methods);
+    syn::custom_keyword!(get);
+    syn::custom_keyword!(set);
+}
+
+/// A single `remote_type!` definition.
+pub enum Definition {
+    Service(Service),
+    Object(Object),
+    Enum(Enum),
+}
+
+/// `service Name { properties: { ... } methods: { ... } }`
+pub struct Service {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub properties: Vec<Property>,
+    pub methods: Vec<Method>,
+}
+
+/// `object Service.Class { properties: { ... } methods: { ... } static_methods: { ... } }`
+pub struct Object {
+    pub attrs: Vec<Attribute>,
+    pub service: Ident,
+    pub name: Ident,
+    pub properties: Vec<Property>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+}
+
+/// `{ RpcName { get: getter -> Type, set: setter(Type) } }`
+pub struct Property {
+    pub name: Ident,
+    pub getter: Accessor,
+    pub setter: Option<Accessor>,
+}
+
+/// The getter or setter half of a property.
+pub struct Accessor {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub ty: Type,
+}
+
+/// `{ fn name(arg: Type, ...) [optional: Type, ...] -> Type { RpcName(arg_expr, ...) } }`
+pub struct Method {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub args: Vec<Argument>,
+    pub optional: Option<Vec<Argument>>,
+    pub return_type: Option<Type>,
+    pub rpc_name: Ident,
+    pub arg_exprs: Vec<Expr>,
+}
+
+/// `name: Type`
+pub struct Argument {
+    pub name: Ident,
+    pub ty: Type,
+}
+
+/// `enum Name { Variant = value, ... }`
+pub struct Enum {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub variants: Vec<Variant>,
+}
+
+pub struct Variant {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub value: Expr,
+}
+
+impl Parse for Definition {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::service) {
+            Service::parse_with_attrs(input, attrs).map(Definition::Service)
+        } else if lookahead.peek(kw::object) {
+            Object::parse_with_attrs(input, attrs).map(Definition::Object)
+        } else if lookahead.peek(Token![enum]) {
+            Enum::parse_with_attrs(input, attrs).map(Definition::Enum)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Service {
+    fn parse_with_attrs(input: ParseStream, attrs: Vec<Attribute>) -> Result<Self> {
+        input.parse::<kw::service>()?;
+        let name = input.parse()?;
+
+        let content;
+        braced!(content in input);
+
+        let mut properties = None;
+        let mut methods = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(kw::properties) {
+                let keyword = content.parse::<kw::properties>()?;
+                set_once(
+                    &mut properties,
+                    keyword.span,
+                    "properties",
+                    parse_section(&content)?,
+                )?;
+            } else if lookahead.peek(kw::methods) {
+                let keyword = content.parse::<kw::methods>()?;
+                set_once(
+                    &mut methods,
+                    keyword.span,
+                    "methods",
+                    parse_section(&content)?,
+                )?;
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+
+        Ok(Service {
+            attrs,
+            name,
+            properties: properties.unwrap_or_default(),
+            methods: methods.unwrap_or_default(),
+        })
+    }
+}
+
+impl Object {
+    fn parse_with_attrs(input: ParseStream, attrs: Vec<Attribute>) -> Result<Self> {
+        input.parse::<kw::object>()?;
+        let service = input.parse()?;
+        input.parse::<Token![.]>()?;
+        let name = input.parse()?;
+
+        let content;
+        braced!(content in input);
+
+        let mut properties = None;
+        let mut methods = None;
+        let mut static_methods = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(kw::properties) {
+                let keyword = content.parse::<kw::properties>()?;
+                set_once(
+                    &mut properties,
+                    keyword.span,
+                    "properties",
+                    parse_section(&content)?,
+                )?;
+            } else if lookahead.peek(kw::methods) {
+                let keyword = content.parse::<kw::methods>()?;
+                set_once(
+                    &mut methods,
+                    keyword.span,
+                    "methods",
+                    parse_section(&content)?,
+                )?;
+            } else if lookahead.peek(kw::static_methods) {
+                let keyword = content.parse::<kw::static_methods>()?;
+                set_once(
+                    &mut static_methods,
+                    keyword.span,
+                    "static_methods",
+                    parse_section(&content)?,
+                )?;
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+
+        Ok(Object {
+            attrs,
+            service,
+            name,
+            properties: properties.unwrap_or_default(),
+            methods: methods.unwrap_or_default(),
+            static_methods: static_methods.unwrap_or_default(),
+        })
+    }
+}
+
+impl Parse for Property {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = Ident::parse_any(input)?;
+
+        let content;
+        braced!(content in input);
+
+        let getter = Accessor::parse_getter(&content)?;
+        let mut setter = None;
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+            if !content.is_empty() {
+                setter = Some(Accessor::parse_setter(&content)?);
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+        }
+
+        if !content.is_empty() {
+            return Err(content.error("unexpected tokens after property accessors"));
+        }
+
+        Ok(Property {
+            name,
+            getter,
+            setter,
+        })
+    }
+}
+
+impl Accessor {
+    fn parse_getter(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<kw::get>()?;
+        input.parse::<Token![:]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![->]>()?;
+        let ty = input.parse()?;
+
+        Ok(Accessor { attrs, name, ty })
+    }
+
+    fn parse_setter(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<kw::set>()?;
+        input.parse::<Token![:]>()?;
+        let name = input.parse()?;
+
+        let content;
+        parenthesized!(content in input);
+        let ty = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("a setter takes exactly one argument type"));
+        }
+
+        Ok(Accessor { attrs, name, ty })
+    }
+}
+
+impl Parse for Method {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<Token![fn]>()?;
+        let name = Ident::parse_any(input)?;
+
+        let content;
+        parenthesized!(content in input);
+        let args = parse_arguments(&content)?;
+
+        let optional = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            Some(parse_arguments(&content)?)
+        } else {
+            None
+        };
+
+        let return_type = if input.peek(Token![->]) {
+            input.parse::<Token![->]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let body;
+        braced!(body in input);
+        let rpc_name = Ident::parse_any(&body)?;
+        let content;
+        parenthesized!(content in body);
+        let arg_exprs = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        if !body.is_empty() {
+            return Err(body.error("expected a single remote procedure invocation"));
+        }
+
+        Ok(Method {
+            attrs,
+            name,
+            args,
+            optional,
+            return_type,
+            rpc_name,
+            arg_exprs,
+        })
+    }
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(Argument { name, ty })
+    }
+}
+
+impl Enum {
+    fn parse_with_attrs(input: ParseStream, attrs: Vec<Attribute>) -> Result<Self> {
+        input.parse::<Token![enum]>()?;
+        let name = input.parse()?;
+
+        let content;
+        let brace = braced!(content in input);
+        let variants: Vec<Variant> = Punctuated::<Variant, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        if variants.is_empty() {
+            return Err(syn::Error::new(
+                brace.span,
+                "a remote enum must have at least one variant",
+            ));
+        }
+
+        Ok(Enum {
+            attrs,
+            name,
+            variants,
+        })
+    }
+}
+
+impl Parse for Variant {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(Variant { attrs, name, value })
+    }
+}
+
+/// Parses `: { { item } { item } ... }` where each item is wrapped in its own braces.
+fn parse_section<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
+    input.parse::<Token![:]>()?;
+
+    let content;
+    braced!(content in input);
+
+    let mut items = Vec::new();
+    while !content.is_empty() {
+        let item;
+        braced!(item in content);
+        items.push(item.parse()?);
+        if !item.is_empty() {
+            return Err(item.error("unexpected tokens after definition"));
+        }
+    }
+
+    Ok(items)
+}
+
+fn parse_arguments(input: ParseStream) -> Result<Vec<Argument>> {
+    Ok(Punctuated::<Argument, Token![,]>::parse_terminated(input)?
+        .into_iter()
+        .collect())
+}
+
+fn set_once<T>(
+    slot: &mut Option<T>,
+    span: proc_macro2::Span,
+    section: &str,
+    value: T,
+) -> Result<()> {
+    if slot.is_some() {
+        return Err(syn::Error::new(
+            span,
+            format!("duplicate `{}` section", section),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Error, Fields, GenericParam, Lifetime, LifetimeDef,
+    Result,
+};
+
+/// Generates `Encode` for a struct, as a `Tuple` of its fields, or for a C-like enum, as the
+/// `i64` value of its discriminant.
+pub fn encode(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let accessors = field_accessors(&data.fields);
+            quote! {
+                ::krpc_bindings::codec::encode_tuple(vec![
+                    #( ::krpc_bindings::codec::Encode::encode(&self.#accessors)?, )*
+                ])
+            }
+        }
+        Data::Enum(data) => {
+            let variants = unit_variants(data)?;
+            quote! {
+                let value: i64 = match self {
+                    #( #name::#variants => #name::#variants as i64, )*
+                };
+                ::krpc_bindings::codec::Encode::encode(&value)
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "Encode cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for ty in field_types(&input.data) {
+            where_clause
+                .predicates
+                .push(syn::parse_quote_spanned!(ty.span()=> #ty: ::krpc_bindings::codec::Encode));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::krpc_bindings::codec::Encode for #name #ty_generics #where_clause {
+            fn encode(&self) -> ::krpc_bindings::codec::CodecResult<Vec<u8>> {
+                #body
+            }
+        }
+    })
+}
+
+/// Generates `Decode` for a struct, from a `Tuple` of its fields, or for a C-like enum, from
+/// the `i64` value of its discriminant.
+pub fn decode(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let count = fields.iter().count();
+            let values = fields.iter().enumerate().map(|(index, field)| {
+                let ty = &field.ty;
+                quote_spanned!(ty.span()=>
+                    <#ty as ::krpc_bindings::codec::Decode>::decode(items[#index], connection)?
+                )
+            });
+            let construct = match fields {
+                Fields::Named(named) => {
+                    let names = named.named.iter().map(|field| &field.ident);
+                    quote!(#name { #( #names: #values, )* })
+                }
+                Fields::Unnamed(_) => quote!(#name( #( #values, )* )),
+                Fields::Unit => quote!(#name),
+            };
+            quote! {
+                let items = ::krpc_bindings::codec::decode_tuple::<Self>(bytes, #count)?;
+                Ok(#construct)
+            }
+        }
+        Data::Enum(data) => {
+            let variants = unit_variants(data)?;
+            quote! {
+                let value = <i64 as ::krpc_bindings::codec::Decode>::decode(bytes, connection)?;
+                #(
+                    if value == #name::#variants as i64 {
+                        return Ok(#name::#variants);
+                    }
+                )*
+                Err(::krpc_bindings::codec::CodecError::InvalidEnumValue {
+                    value,
+                    type_name: ::std::any::type_name::<Self>(),
+                }
+                .into())
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "Decode cannot be derived for unions",
+            ))
+        }
+    };
+
+    // The connection lifetime is added alongside the type's own generics, and each field is
+    // required to be decodable with it.
+    let connection_lifetime = Lifetime::new("'__krpc_connection", Span::call_site());
+    let mut generics = input.generics.clone();
+    generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeDef::new(connection_lifetime.clone())),
+    );
+    {
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let where_clause = generics.make_where_clause();
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#name #ty_generics: #connection_lifetime));
+        for ty in field_types(&input.data) {
+            where_clause
+                .predicates
+                .push(syn::parse_quote_spanned!(ty.span()=>
+                    #ty: ::krpc_bindings::codec::Decode<#connection_lifetime>
+                ));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::krpc_bindings::codec::Decode<#connection_lifetime> for #name #ty_generics #where_clause {
+            fn decode(
+                bytes: &[u8],
+                connection: &#connection_lifetime ::krpc_bindings::client::Connection,
+            ) -> ::krpc_bindings::codec::CodecResult<Self> {
+                #body
+            }
+        }
+    })
+}
+
+fn field_accessors(fields: &Fields) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        })
+        .collect()
+}
+
+fn field_types(data: &Data) -> Vec<&syn::Type> {
+    match data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the variants of a C-like enum, rejecting variants that have fields.
+fn unit_variants(data: &DataEnum) -> Result<Vec<&syn::Ident>> {
+    data.variants
+        .iter()
+        .map(|variant| match variant.fields {
+            Fields::Unit => Ok(&variant.ident),
+            _ => Err(Error::new(
+                variant.fields.span(),
+                "only enums without fields can be encoded as a kRPC enum",
+            )),
+        })
+        .collect()
+}
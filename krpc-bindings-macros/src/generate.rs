@@ -0,0 +1,907 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{Attribute, Expr, Ident, Type};
+
+use crate::definition::{Argument, Definition, Enum, Method, Object, Property, Service};
+
+pub fn definition(definition: &Definition) -> TokenStream {
+    match definition {
+        Definition::Service(service) => generate_service(service),
+        Definition::Object(object) => generate_object(object),
+        Definition::Enum(remote_enum) => generate_enum(remote_enum),
+    }
+}
+
+/// How a generated procedure is bound to the type that exposes it.
+#[derive(Copy, Clone, PartialEq)]
+enum Receiver {
+    /// A service method, invoked through `&self`.
+    Service,
+    /// A remote object method, invoked through `&self` with the object as the first argument.
+    Object,
+    /// A static remote object method, invoked with an explicit `connection`.
+    Static,
+}
+
+/// A single remote procedure, normalised from a property accessor or method definition.
+struct Procedure<'d> {
+    attrs: &'d [Attribute],
+    name: &'d Ident,
+    args: Vec<(Ident, &'d Type)>,
+    optional: Option<&'d [Argument]>,
+    return_type: Option<&'d Type>,
+    service: String,
+    procedure: String,
+    arg_exprs: Vec<TokenStream>,
+    receiver: Receiver,
+}
+
+impl<'d> Procedure<'d> {
+    fn from_method(
+        method: &'d Method,
+        service: &Ident,
+        class: Option<&Ident>,
+        receiver: Receiver,
+    ) -> Self {
+        let procedure = match (class, receiver) {
+            (Some(class), Receiver::Static) => format!("{}_static_{}", class, method.rpc_name),
+            (Some(class), _) => format!("{}_{}", class, method.rpc_name),
+            (None, _) => method.rpc_name.to_string(),
+        };
+
+        Procedure {
+            attrs: &method.attrs,
+            name: &method.name,
+            args: method
+                .args
+                .iter()
+                .map(|arg| (arg.name.clone(), &arg.ty))
+                .collect(),
+            optional: method.optional.as_deref(),
+            return_type: method.return_type.as_ref(),
+            service: service.to_string(),
+            procedure,
+            arg_exprs: method.arg_exprs.iter().map(encodable).collect(),
+            receiver,
+        }
+    }
+
+    fn from_property(
+        property: &'d Property,
+        service: &Ident,
+        class: Option<&Ident>,
+        receiver: Receiver,
+    ) -> Vec<Self> {
+        let prefix = match class {
+            Some(class) => format!("{}_", class),
+            None => String::new(),
+        };
+
+        let mut procedures = vec![Procedure {
+            attrs: &property.getter.attrs,
+            name: &property.getter.name,
+            args: Vec::new(),
+            optional: None,
+            return_type: Some(&property.getter.ty),
+            service: service.to_string(),
+            procedure: format!("{}get_{}", prefix, property.name),
+            arg_exprs: Vec::new(),
+            receiver,
+        }];
+
+        if let Some(setter) = &property.setter {
+            let value = Ident::new("value", setter.name.span());
+            procedures.push(Procedure {
+                attrs: &setter.attrs,
+                name: &setter.name,
+                args: vec![(value.clone(), &setter.ty)],
+                optional: None,
+                return_type: None,
+                service: service.to_string(),
+                procedure: format!("{}set_{}", prefix, property.name),
+                arg_exprs: vec![quote!(#value)],
+                receiver,
+            });
+        }
+
+        procedures
+    }
+
+    fn is_builder(&self) -> bool {
+        self.optional.is_some()
+    }
+
+    /// The parameter list, including the receiver.
+    fn params(&self) -> TokenStream {
+        let names = self.args.iter().map(|(name, _)| name);
+        let types = self.args.iter().map(|(_, ty)| ty);
+        match self.receiver {
+            Receiver::Static => {
+                quote!(connection: &'a ::krpc_bindings::client::Connection #(, #names: #types)*)
+            }
+            _ => quote!(&self #(, #names: #types)*),
+        }
+    }
+
+    fn connection(&self) -> TokenStream {
+        match self.receiver {
+            Receiver::Static => quote!(connection),
+            _ => quote!(self.connection),
+        }
+    }
+
+    /// The expressions that encode each argument, in position order. `this` is a reference to
+    /// the value used to encode the remote object itself.
+    fn encoded_args(&self, this: TokenStream) -> Vec<TokenStream> {
+        let mut values = Vec::with_capacity(self.arg_exprs.len() + 1);
+        if self.receiver == Receiver::Object {
+            values.push(this);
+        }
+        values.extend(self.arg_exprs.iter().map(|expr| quote!(&#expr)));
+
+        let Procedure {
+            service, procedure, ..
+        } = self;
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(position, value)| {
+                quote! {
+                    ::krpc_bindings::codec::encode_argument(#value, #service, #procedure, #position)
+                }
+            })
+            .collect()
+    }
+
+    /// The expression that decodes `response` as the return type.
+    fn decoded_result(&self, response: TokenStream, connection: TokenStream) -> TokenStream {
+        let Procedure {
+            service, procedure, ..
+        } = self;
+        let return_type = match self.return_type {
+            Some(return_type) => quote!(#return_type),
+            None => quote!(()),
+        };
+        quote! {
+            ::krpc_bindings::codec::decode_result::<#return_type>(&#response, #connection, #service, #procedure)
+        }
+    }
+}
+
+/// Wraps an argument expression in parentheses if it would otherwise bind looser than `.encode()`.
+fn encodable(expr: &Expr) -> TokenStream {
+    match expr {
+        Expr::Path(_)
+        | Expr::Lit(_)
+        | Expr::MethodCall(_)
+        | Expr::Call(_)
+        | Expr::Field(_)
+        | Expr::Index(_)
+        | Expr::Tuple(_)
+        | Expr::Paren(_)
+        | Expr::Macro(_) => quote!(#expr),
+        _ => quote!((#expr)),
+    }
+}
+
+/// Returns whether `ty` mentions the lifetime `'name` anywhere.
+fn mentions_lifetime(ty: &Type, name: &str) -> bool {
+    fn search(tokens: TokenStream, name: &str) -> bool {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        tokens.iter().enumerate().any(|(index, token)| match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                matches!(tokens.get(index + 1), Some(TokenTree::Ident(ident)) if ident == name)
+            }
+            TokenTree::Group(group) => search(group.stream(), name),
+            _ => false,
+        })
+    }
+
+    search(quote!(#ty), name)
+}
+
+fn camel_case(ident: &Ident) -> String {
+    ident
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+//
+// Services
+//
+
+fn generate_service(service: &Service) -> TokenStream {
+    let Service {
+        attrs,
+        name,
+        properties,
+        methods,
+    } = service;
+    let name_str = name.to_string();
+
+    let mut procedures: Vec<Procedure> = properties
+        .iter()
+        .flat_map(|prop| Procedure::from_property(prop, name, None, Receiver::Service))
+        .collect();
+    procedures.extend(
+        methods
+            .iter()
+            .map(|method| Procedure::from_method(method, name, None, Receiver::Service)),
+    );
+
+    let invokes = procedures
+        .iter()
+        .filter(|p| !p.is_builder())
+        .map(invoke_method);
+    let builders = procedures
+        .iter()
+        .filter(|p| p.is_builder())
+        .map(|p| builder(name, p));
+
+    let stream_name = format_ident!("{}Stream", name);
+    let streams: Vec<_> = procedures
+        .iter()
+        .filter(|p| !p.is_builder() && p.return_type.is_some())
+        .map(stream_method)
+        .collect();
+    let stream = if streams.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #[derive(Debug, Clone)]
+            pub struct #stream_name<'a> {
+                connection: &'a ::krpc_bindings::client::Connection,
+            }
+
+            impl<'a> #name<'a> {
+                /// Returns a stream instance that provides streaming versions of the
+                /// property getters and methods with return values.
+                pub fn stream(&self) -> #stream_name<'a> {
+                    #stream_name::new(self.connection)
+                }
+            }
+
+            impl<'a> #stream_name<'a> {
+                pub fn new(connection: &'a ::krpc_bindings::client::Connection) -> Self {
+                    Self { connection }
+                }
+
+                #(#streams)*
+            }
+        }
+    };
+
+    let call_name = format_ident!("{}Call", name);
+    let calls: Vec<_> = procedures
+        .iter()
+        .filter(|p| !p.is_builder())
+        .map(call_method)
+        .collect();
+    let call = if calls.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #[derive(Debug, Clone)]
+            pub struct #call_name<'a> {
+                connection: &'a ::krpc_bindings::client::Connection,
+            }
+
+            impl<'a> #name<'a> {
+                /// Returns a call instance that provides versions of the properties
+                /// and methods as `ProcedureCall`s.
+                pub fn call(&self) -> #call_name<'a> {
+                    #call_name::new(self.connection)
+                }
+            }
+
+            impl<'a> #call_name<'a> {
+                pub fn new(connection: &'a ::krpc_bindings::client::Connection) -> Self {
+                    Self { connection }
+                }
+
+                #(#calls)*
+            }
+        }
+    };
+
+    quote! {
+        #(#attrs)*
+        #[derive(Clone)]
+        pub struct #name<'a> {
+            connection: &'a ::krpc_bindings::client::Connection,
+        }
+
+        impl<'a> #name<'a> {
+            /// Creates a new service using the given `connection`.
+            pub fn new(connection: &'a ::krpc_bindings::client::Connection) -> Self {
+                Self { connection }
+            }
+
+            #(#invokes)*
+        }
+
+        impl<'a> std::fmt::Debug for #name<'a> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+                write!(f, "{}", #name_str)
+            }
+        }
+
+        #stream
+        #call
+        #(#builders)*
+    }
+}
+
+//
+// Remote Objects
+//
+
+fn generate_object(object: &Object) -> TokenStream {
+    let Object {
+        attrs,
+        service,
+        name,
+        properties,
+        methods,
+        static_methods,
+    } = object;
+    let service_str = service.to_string();
+    let name_str = name.to_string();
+
+    let mut procedures: Vec<Procedure> = properties
+        .iter()
+        .flat_map(|prop| Procedure::from_property(prop, service, Some(name), Receiver::Object))
+        .collect();
+    procedures.extend(
+        methods
+            .iter()
+            .map(|method| Procedure::from_method(method, service, Some(name), Receiver::Object)),
+    );
+    procedures.extend(
+        static_methods
+            .iter()
+            .map(|method| Procedure::from_method(method, service, Some(name), Receiver::Static)),
+    );
+
+    let invokes = procedures
+        .iter()
+        .filter(|p| !p.is_builder())
+        .map(invoke_method);
+    let builders = procedures
+        .iter()
+        .filter(|p| p.is_builder())
+        .map(|p| builder(name, p));
+
+    let stream_name = format_ident!("{}Stream", name);
+    let call_name = format_ident!("{}Call", name);
+    let streams: Vec<_> = procedures
+        .iter()
+        .filter(|p| !p.is_builder() && p.return_type.is_some())
+        .map(stream_method)
+        .collect();
+    // Classes that only have static methods don't get an accessor, which would otherwise clash
+    // with static methods such as `Expression::call`.
+    let has_instance_procedures = procedures
+        .iter()
+        .any(|p| !p.is_builder() && p.receiver != Receiver::Static);
+    let stream_accessor = if has_instance_procedures {
+        quote! {
+            impl<'a> #name<'a> {
+                /// Returns a stream instance that provides streaming versions of the
+                /// property getters and methods with return values.
+                pub fn stream(&self) -> #stream_name<'a> {
+                    #stream_name::new(self)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+    let call_accessor = if has_instance_procedures {
+        quote! {
+            impl<'a> #name<'a> {
+                /// Returns a call instance that provides versions of the properties
+                /// and methods as `ProcedureCall`s.
+                pub fn call(&self) -> #call_name<'a> {
+                    #call_name::new(self)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let stream = if streams.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #[derive(Debug, Clone)]
+            pub struct #stream_name<'a> {
+                connection: &'a ::krpc_bindings::client::Connection,
+                id: u64
+            }
+
+            #stream_accessor
+
+            impl<'a> #stream_name<'a> {
+                pub fn new(remote_object: &#name<'a>) -> Self {
+                    Self {
+                        connection: remote_object.connection,
+                        id: remote_object.id
+                    }
+                }
+
+                #(#streams)*
+            }
+        }
+    };
+
+    let calls: Vec<_> = procedures
+        .iter()
+        .filter(|p| !p.is_builder())
+        .map(call_method)
+        .collect();
+    let call = if calls.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            #[derive(Debug, Clone)]
+            pub struct #call_name<'a> {
+                connection: &'a ::krpc_bindings::client::Connection,
+                id: u64
+            }
+
+            #call_accessor
+
+            impl<'a> #call_name<'a> {
+                pub fn new(remote_object: &#name<'a>) -> Self {
+                    Self {
+                        connection: remote_object.connection,
+                        id: remote_object.id
+                    }
+                }
+
+                #(#calls)*
+            }
+        }
+    };
+
+    quote! {
+        #(#attrs)*
+        #[derive(Clone)]
+        pub struct #name<'a> {
+            #[allow(dead_code)]
+            connection: &'a ::krpc_bindings::client::Connection,
+            id: u64
+        }
+
+        impl<'a> ::krpc_bindings::RemoteObject<'a> for #name<'a> {
+            fn new(connection: &'a ::krpc_bindings::client::Connection, id: u64) -> Self {
+                Self { connection, id }
+            }
+
+            fn id(&self) -> u64 { self.id }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Decode<'a> for #name<'a> {
+            fn decode(bytes: &[u8], connection: &'a ::krpc_bindings::client::Connection) -> ::krpc_bindings::codec::CodecResult<Self> {
+                let id = <u64 as ::krpc_bindings::codec::Decode>::decode(bytes, connection)?;
+                if id == 0 {
+                    Err(::krpc_bindings::codec::Error::from(::krpc_bindings::codec::CodecError::NullValue))
+                } else {
+                    Ok(<#name as ::krpc_bindings::RemoteObject>::new(connection, id))
+                }
+            }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Decode<'a> for Option<#name<'a>> {
+            fn decode(bytes: &[u8], connection: &'a ::krpc_bindings::client::Connection) -> ::krpc_bindings::codec::CodecResult<Self> {
+                let id = <u64 as ::krpc_bindings::codec::Decode>::decode(bytes, connection)?;
+                if id == 0 {
+                    Ok(None)
+                } else {
+                    Ok(Some(<#name as ::krpc_bindings::RemoteObject>::new(connection, id)))
+                }
+            }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Encode for #name<'a> {
+            fn encode(&self) -> ::krpc_bindings::codec::CodecResult<Vec<u8>> {
+                ::krpc_bindings::codec::Encode::encode(&self.id)
+            }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Encode for Option<#name<'a>> {
+            fn encode(&self) -> ::krpc_bindings::codec::CodecResult<Vec<u8>> {
+                match self {
+                    None => ::krpc_bindings::codec::Encode::encode(&0u64),
+                    Some(obj) => ::krpc_bindings::codec::Encode::encode(&obj.id)
+                }
+            }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Encode for Option<&#name<'a>> {
+            fn encode(&self) -> ::krpc_bindings::codec::CodecResult<Vec<u8>> {
+                match self {
+                    None => ::krpc_bindings::codec::Encode::encode(&0u64),
+                    Some(obj) => ::krpc_bindings::codec::Encode::encode(&obj.id)
+                }
+            }
+        }
+
+        impl<'a> std::fmt::Debug for #name<'a> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+                write!(f, "{}.{}{{ id: {} }}", #service_str, #name_str, self.id)
+            }
+        }
+
+        // Two handles refer to the same remote object when they have the same id on the same
+        // connection. Connections are compared by address, as ids are only unique per server.
+        impl<'a> PartialEq for #name<'a> {
+            fn eq(&self, other: &Self) -> bool {
+                self.id == other.id && std::ptr::eq(self.connection, other.connection)
+            }
+        }
+
+        impl<'a> Eq for #name<'a> {}
+
+        impl<'a> std::hash::Hash for #name<'a> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.id.hash(state);
+                std::ptr::hash(self.connection, state);
+            }
+        }
+
+        impl<'a> PartialOrd for #name<'a> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        // Objects are ordered by id. Objects with the same id on different connections are
+        // ordered by the client identifiers the server gave the connections, rather than by
+        // address, so that the order does not depend on where the connections were allocated.
+        impl<'a> Ord for #name<'a> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.id.cmp(&other.id).then_with(|| {
+                    if std::ptr::eq(self.connection, other.connection) {
+                        std::cmp::Ordering::Equal
+                    } else {
+                        self.connection.id().cmp(other.connection.id())
+                    }
+                })
+            }
+        }
+
+        // A handle is only meaningful together with its connection, so objects serialize to
+        // their type and id and cannot be deserialized.
+        #[cfg(feature = "serde")]
+        impl<'a> ::serde::Serialize for #name<'a> {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeStruct;
+
+                let mut state = serializer.serialize_struct(#name_str, 2)?;
+                state.serialize_field("type", concat!(#service_str, ".", #name_str))?;
+                state.serialize_field("id", &self.id)?;
+                state.end()
+            }
+        }
+
+        impl<'a> #name<'a> {
+            #(#invokes)*
+        }
+
+        #stream
+        #call
+        #(#builders)*
+    }
+}
+
+//
+// Procedures
+//
+
+fn invoke_method(p: &Procedure) -> TokenStream {
+    let Procedure {
+        attrs,
+        name,
+        service,
+        procedure,
+        ..
+    } = p;
+    let params = p.params();
+    let connection = p.connection();
+    let args = p.encoded_args(quote!(self));
+    let result = p.decoded_result(quote!(response), connection.clone());
+
+    match p.return_type {
+        Some(return_type) => quote! {
+            #(#attrs)*
+            pub fn #name(#params) -> ::krpc_bindings::client::KrpcResult<#return_type> {
+                let args: Vec<Vec<u8>> = vec![#(#args?),*];
+
+                let response = #connection.invoke(#service, #procedure, &args)?;
+                #result
+            }
+        },
+        None => quote! {
+            #(#attrs)*
+            pub fn #name(#params) -> ::krpc_bindings::client::KrpcResult<()> {
+                let args: Vec<Vec<u8>> = vec![#(#args?),*];
+
+                #connection.invoke(#service, #procedure, &args)?;
+                Ok(())
+            }
+        },
+    }
+}
+
+fn stream_method(p: &Procedure) -> TokenStream {
+    let Procedure {
+        attrs,
+        name,
+        service,
+        procedure,
+        ..
+    } = p;
+    let params = p.params();
+    let connection = p.connection();
+    let args = p.encoded_args(quote!(&self.id));
+    let return_type = p.return_type;
+    // A return type that names the connection lifetime, e.g. `Option<Orbit<'a>>`, can only be
+    // decoded by a stream with that same lifetime.
+    let names_lifetime = return_type.is_some_and(|ty| mentions_lifetime(ty, "a"));
+    let stream_type = if p.receiver == Receiver::Static || names_lifetime {
+        quote!(::krpc_bindings::client::Stream<'a, #return_type>)
+    } else {
+        quote!(::krpc_bindings::client::Stream<#return_type>)
+    };
+
+    quote! {
+        #(#attrs)*
+        pub fn #name(#params) -> ::krpc_bindings::client::KrpcResult<#stream_type> {
+            let args: Vec<Vec<u8>> = vec![#(#args?),*];
+
+            #connection.add_stream(#service, #procedure, &args)
+        }
+    }
+}
+
+fn call_method(p: &Procedure) -> TokenStream {
+    let Procedure {
+        attrs,
+        name,
+        service,
+        procedure,
+        ..
+    } = p;
+    let params = p.params();
+    let connection = p.connection();
+    let args = p.encoded_args(quote!(&self.id));
+
+    quote! {
+        #(#attrs)*
+        pub fn #name(#params) -> ::krpc_bindings::client::KrpcResult<::krpc_bindings::client::ProcedureCall> {
+            let args: Vec<Vec<u8>> = vec![#(#args?),*];
+
+            Ok(#connection.procedure_call(#service, #procedure, &args))
+        }
+    }
+}
+
+//
+// Builders
+//
+
+fn builder(owner: &Ident, p: &Procedure) -> TokenStream {
+    let Procedure {
+        attrs,
+        name,
+        service,
+        procedure,
+        ..
+    } = p;
+    let builder = format_ident!("{}{}", owner, camel_case(name));
+    let builder_str = builder.to_string();
+    let params = p.params();
+    let connection = p.connection();
+
+    let args = p.encoded_args(quote!(self));
+    let result = p.decoded_result(quote!(response), quote!(connection));
+
+    let optional = p.optional.unwrap_or(&[]);
+    let opt_names: Vec<_> = optional.iter().map(|arg| &arg.name).collect();
+    let opt_types = optional.iter().map(|arg| &arg.ty);
+    let opt_positions = (args.len()..).take(optional.len());
+    let opt_docs = opt_names
+        .iter()
+        .map(|name| format!("Sets the optional `{}` argument.", name));
+
+    let (return_type, stream) = match p.return_type {
+        Some(return_type) => (
+            quote!(#return_type),
+            quote! {
+                /// Returns a stream of the procedure's return value.
+                pub fn stream(self) -> ::krpc_bindings::client::KrpcResult<::krpc_bindings::client::Stream<'a, #return_type>> {
+                    let connection = self.connection;
+                    let args = self.arguments()?;
+
+                    connection.add_stream_with_defaults(#service, #procedure, &args)
+                }
+            },
+        ),
+        None => (quote!(()), quote!()),
+    };
+
+    quote! {
+        impl<'a> #owner<'a> {
+            #(#attrs)*
+            pub fn #name(#params) -> #builder<'a> {
+                #builder {
+                    connection: #connection,
+                    args: vec![#(#args),*],
+                    #( #opt_names: None, )*
+                }
+            }
+        }
+
+        /// Builds a call to a procedure that has optional arguments. Any optional argument
+        /// that is not set is omitted from the request, so the server uses its default value.
+        pub struct #builder<'a> {
+            connection: &'a ::krpc_bindings::client::Connection,
+            args: Vec<::krpc_bindings::codec::CodecResult<Vec<u8>>>,
+            #( #opt_names: Option<::krpc_bindings::codec::CodecResult<Vec<u8>>>, )*
+        }
+
+        impl<'a> #builder<'a> {
+            #(
+                #[doc = #opt_docs]
+                pub fn #opt_names(mut self, value: #opt_types) -> Self {
+                    self.#opt_names = Some(::krpc_bindings::codec::encode_argument(
+                        &value,
+                        #service,
+                        #procedure,
+                        #opt_positions,
+                    ));
+                    self
+                }
+            )*
+
+            /// Invokes the procedure.
+            pub fn call(self) -> ::krpc_bindings::client::KrpcResult<#return_type> {
+                let connection = self.connection;
+                let args = self.arguments()?;
+
+                let response = connection.invoke_with_defaults(#service, #procedure, &args)?;
+                #result
+            }
+
+            #stream
+
+            /// Returns the procedure as a `ProcedureCall`.
+            pub fn procedure_call(self) -> ::krpc_bindings::client::KrpcResult<::krpc_bindings::client::ProcedureCall> {
+                let connection = self.connection;
+                let args = self.arguments()?;
+
+                Ok(connection.procedure_call_with_defaults(#service, #procedure, &args))
+            }
+
+            fn arguments(self) -> ::krpc_bindings::codec::CodecResult<Vec<Option<Vec<u8>>>> {
+                let mut args = Vec::with_capacity(self.args.len());
+                for arg in self.args {
+                    args.push(Some(arg?));
+                }
+
+                #(
+                    args.push(match self.#opt_names {
+                        Some(value) => Some(value?),
+                        None => None,
+                    });
+                )*
+
+                Ok(args)
+            }
+        }
+
+        impl<'a> std::fmt::Debug for #builder<'a> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+                write!(f, "{}", #builder_str)
+            }
+        }
+    }
+}
+
+//
+// Remote Enums
+//
+
+fn generate_enum(remote_enum: &Enum) -> TokenStream {
+    let Enum {
+        attrs,
+        name,
+        variants,
+    } = remote_enum;
+    let variant_attrs = variants.iter().map(|v| &v.attrs);
+    let variant_names: Vec<_> = variants.iter().map(|v| &v.name).collect();
+    let variant_values: Vec<_> = variants.iter().map(|v| &v.value).collect();
+    let variant_strs: Vec<_> = variants.iter().map(|v| v.name.to_string()).collect();
+    let variant_indices = 0..variants.len() as u32;
+
+    quote! {
+        #(#attrs)*
+        #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+        pub enum #name {
+            #(
+                #(#variant_attrs)*
+                #variant_names = #variant_values
+            ),*
+        }
+
+        impl ::krpc_bindings::RemoteEnum for #name {
+            fn from_value(value: i64) -> Option<Self> {
+                match value {
+                    #( #variant_values => Some(#name::#variant_names), )*
+                    _ => None
+                }
+            }
+
+            fn value(&self) -> i64 {
+                *self as i64
+            }
+        }
+
+        impl<'a> ::krpc_bindings::codec::Decode<'a> for #name {
+            fn decode(bytes: &[u8], connection: &'a ::krpc_bindings::client::Connection) -> ::krpc_bindings::codec::CodecResult<Self> {
+                let value = <i64 as ::krpc_bindings::codec::Decode>::decode(bytes, connection)?;
+                Ok(<Self as ::krpc_bindings::RemoteEnum>::from_value(value)
+                    .ok_or(::krpc_bindings::codec::CodecError::InvalidEnumValue {
+                        value,
+                        type_name: stringify!(#name),
+                    })?)
+            }
+        }
+
+        impl ::krpc_bindings::codec::Encode for #name {
+            fn encode(&self) -> ::krpc_bindings::codec::CodecResult<Vec<u8>> {
+                ::krpc_bindings::codec::Encode::encode(&(*self as i64))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for #name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    #(
+                        #name::#variant_names => serializer.serialize_unit_variant(
+                            stringify!(#name),
+                            #variant_indices,
+                            #variant_strs,
+                        ),
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for #name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const VARIANTS: &[&str] = &[#(#variant_strs),*];
+
+                let variant = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                match variant.as_str() {
+                    #( #variant_strs => Ok(#name::#variant_names), )*
+                    _ => Err(<D::Error as ::serde::de::Error>::unknown_variant(&variant, VARIANTS)),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Procedural macros used to declare the kRPC service bindings.
+extern crate proc_macro;
+
+mod definition;
+mod derive;
+mod generate;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+use crate::definition::Definition;
+
+/// Declares a remote service, remote object or remote enum.
+///
+/// # Services
+///
+/// ```ignore
+/// remote_type!(
+/// /// Documentation for the service.
+/// service KRPC {
+///     properties: {
+///         {
+///             Paused {
+///                 /// Documentation for the getter.
+///                 get: is_paused -> bool,
+///                 /// Documentation for the setter.
+///                 set: set_paused(bool)
+///             }
+///         }
+///     }
+///     methods: {
+///         {
+///             /// Documentation for the method.
+///             fn get_status() -> Status {
+///                 GetStatus()
+///             }
+///         }
+///     }
+/// });
+/// ```
+///
+/// # Remote Objects
+///
+/// Remote objects are declared with `object Service.Class { ... }` and accept the optional
+/// sections `properties`, `methods` and `static_methods`. Static methods take the connection
+/// as their first argument.
+///
+/// A return type may name the connection lifetime `'a`, e.g. `-> Option<Orbit<'a>>`, so the
+/// returned object is not tied to the borrow of the object it was obtained from.
+///
+/// Remote objects implement `PartialEq`, `Eq`, `Hash` and `Ord`. Two objects are equal when
+/// they have the same id and were obtained from the same `Connection`, and they are ordered by
+/// id. With the `serde` feature, they serialize as `{"type": "Service.Class", "id": ...}`.
+///
+/// # Remote Enums
+///
+/// ```ignore
+/// remote_type!(
+/// enum GameScene {
+///     SpaceCenter = 0,
+///     Flight = 1,
+/// });
+/// ```
+///
+/// With the `serde` feature, remote enums serialize and deserialize by variant name.
+///
+/// # Optional Arguments
+///
+/// A method whose parameter list is followed by `[name: Type, ...]` returns a builder. Each
+/// optional argument gets a setter on the builder, and any argument that is not set is left
+/// for the server to default. The return type of such a method must name its lifetime
+/// explicitly, e.g. `-> Flight<'a>`.
+///
+/// # Companions
+///
+/// Every service and remote object also gets a `*Stream` type, with streaming versions of the
+/// getters and methods that return a value, and a `*Call` type, with versions of the
+/// properties and methods that return a `ProcedureCall`. Static methods are available on
+/// both as associated functions.
+///
+/// # Errors
+///
+/// A failure to encode an argument or decode the return value is reported as a
+/// `ProcedureCodecError`, naming the service, the procedure, the argument position and the
+/// Rust type involved.
+#[proc_macro]
+pub fn remote_type(input: TokenStream) -> TokenStream {
+    let definition = parse_macro_input!(input as Definition);
+    generate::definition(&definition).into()
+}
+
+/// Derives `Encode` for a struct or a C-like enum.
+///
+/// A struct is encoded as a kRPC `Tuple` of its fields, in declaration order, so it can be
+/// passed wherever the server expects a tuple. A C-like enum is encoded as the `i64` value of
+/// its discriminant, like the enums declared with `remote_type!`.
+///
+/// ```ignore
+/// use krpc_bindings::codec::{Decode, Encode};
+///
+/// #[derive(Encode, Decode)]
+/// struct PidGains {
+///     kp: f64,
+///     ki: f64,
+///     kd: f64,
+/// }
+/// ```
+#[proc_macro_derive(Encode)]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::encode(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `Decode` for a struct or a C-like enum, using the same representation as
+/// `#[derive(Encode)]`.
+///
+/// Decoding a struct fails if the tuple does not have exactly one item per field, and decoding
+/// an enum fails if the value does not match any of its discriminants.
+#[proc_macro_derive(Decode)]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::decode(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
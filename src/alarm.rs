@@ -0,0 +1,57 @@
+//! A common interface to the alarms of the stock alarm clock and of Kerbal Alarm Clock.
+
+use crate::client::KrpcResult;
+use crate::kac;
+use crate::spacecenter;
+
+/// An alarm that fires at a given time, from either the stock alarm clock
+/// (`spacecenter::Alarm`) or Kerbal Alarm Clock (`kac::Alarm`).
+pub trait ScheduledAlarm {
+    /// Returns the title of the alarm.
+    fn title(&self) -> KrpcResult<String>;
+
+    /// Returns the description of the alarm.
+    fn description(&self) -> KrpcResult<String>;
+
+    /// Returns the universal time at which the alarm will fire.
+    fn time(&self) -> KrpcResult<f64>;
+
+    /// Returns the number of seconds until the alarm will fire.
+    fn time_until(&self) -> KrpcResult<f64>;
+}
+
+impl<'a> ScheduledAlarm for spacecenter::Alarm<'a> {
+    fn title(&self) -> KrpcResult<String> {
+        spacecenter::Alarm::title(self)
+    }
+
+    fn description(&self) -> KrpcResult<String> {
+        spacecenter::Alarm::description(self)
+    }
+
+    fn time(&self) -> KrpcResult<f64> {
+        spacecenter::Alarm::time(self)
+    }
+
+    fn time_until(&self) -> KrpcResult<f64> {
+        self.time_till()
+    }
+}
+
+impl<'a> ScheduledAlarm for kac::Alarm<'a> {
+    fn title(&self) -> KrpcResult<String> {
+        self.name()
+    }
+
+    fn description(&self) -> KrpcResult<String> {
+        self.notes()
+    }
+
+    fn time(&self) -> KrpcResult<f64> {
+        kac::Alarm::time(self)
+    }
+
+    fn time_until(&self) -> KrpcResult<f64> {
+        self.remaining()
+    }
+}
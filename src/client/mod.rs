@@ -1,4 +1,4 @@
-use crate::codec::{Decode, Encode};
+use crate::codec::{decode_result, Decode, Encode};
 use crate::krpc::Expression;
 
 use failure::Error;
@@ -13,7 +13,7 @@ mod stream;
 
 pub use self::error::*;
 pub use self::schema::{Argument, ProcedureCall, Services, Status};
-pub use self::stream::{Event, Stream};
+pub use self::stream::{Event, Stream, StreamWait};
 
 use self::rpc::Rpc;
 
@@ -32,14 +32,46 @@ fn recv_msg<T: protobuf::Message>(socket: &mut TcpStream) -> KrpcResult<T> {
     Ok(cis.read_message()?)
 }
 
-fn convert_procedure_result(result: &schema::ProcedureResult) -> Result<Vec<u8>, ResponseError> {
+fn convert_procedure_result(mut result: schema::ProcedureResult) -> Result<Vec<u8>, ResponseError> {
     if result.has_error() {
         Err(ResponseError::from(result.get_error()))
     } else {
-        Ok(Vec::from(result.get_value()))
+        Ok(result.take_value())
     }
 }
 
+/// Takes the next result from those returned by `Connection::invoke_batch`, and decodes it as
+/// the return value of `procedure`.
+pub(crate) fn decode_batch_result<'a, T, I>(
+    results: &mut I,
+    connection: &'a Connection,
+    service: &'static str,
+    procedure: &'static str,
+) -> KrpcResult<T>
+where
+    T: Decode<'a>,
+    I: Iterator<Item = Result<Vec<u8>, ResponseError>>,
+{
+    match results.next() {
+        Some(result) => Ok(decode_result(&result?, connection, service, procedure)?),
+        None => Err(Error::from(ResponseError::MissingResult)),
+    }
+}
+
+/// Takes the next result from those returned by `Connection::invoke_batch`, and decodes it as
+/// the return value of the `SpaceCenter` procedure `procedure`. See `decode_batch_result`.
+pub(crate) fn decode_space_center_result<'a, T, I>(
+    results: &mut I,
+    connection: &'a Connection,
+    procedure: &'static str,
+) -> KrpcResult<T>
+where
+    T: Decode<'a>,
+    I: Iterator<Item = Result<Vec<u8>, ResponseError>>,
+{
+    decode_batch_result(results, connection, "SpaceCenter", procedure)
+}
+
 pub struct Connection {
     name: String,
     rpc: rpc::Rpc,
@@ -92,6 +124,32 @@ impl Connection {
         self.rpc.invoke(service, procedure, args)
     }
 
+    /// Invokes a procedure where `None` arguments are omitted from the request, so that the
+    /// server uses the parameter's default value instead.
+    pub fn invoke_with_defaults(
+        &self,
+        service: &str,
+        procedure: &str,
+        args: &[Option<Vec<u8>>],
+    ) -> KrpcResult<Vec<u8>> {
+        self.rpc.invoke_procedure_call(Rpc::create_procedure_call_with_defaults(
+            service, procedure, args,
+        ))
+    }
+
+    /// Invokes all of the `calls` in a single request, such as those returned by the `*Call`
+    /// companions of services and remote objects. The server executes them together, so their
+    /// results describe the same instant of the game.
+    ///
+    /// The results are in the same order as the calls. The outer error is for a failure of the
+    /// request as a whole, and each inner error is for a call that failed on its own.
+    pub fn invoke_batch(
+        &self,
+        calls: Vec<ProcedureCall>,
+    ) -> KrpcResult<Vec<Result<Vec<u8>, ResponseError>>> {
+        self.rpc.invoke_batch(calls)
+    }
+
     pub fn procedure_call(
         &self,
         service: &str,
@@ -101,6 +159,17 @@ impl Connection {
         Rpc::create_procedure_call(service, procedure, args)
     }
 
+    /// Creates a `ProcedureCall` where `None` arguments are omitted, so that the server uses
+    /// the parameter's default value instead.
+    pub fn procedure_call_with_defaults(
+        &self,
+        service: &str,
+        procedure: &str,
+        args: &[Option<Vec<u8>>],
+    ) -> ProcedureCall {
+        Rpc::create_procedure_call_with_defaults(service, procedure, args)
+    }
+
     pub fn add_event<'a>(&'a self, expr: &Expression) -> KrpcResult<Event<'a>> {
         let args = vec![expr.encode()?];
         let response = self.rpc.invoke("KRPC", "AddEvent", &args)?;
@@ -118,10 +187,27 @@ impl Connection {
         procedure: &str,
         args: &[Vec<u8>],
     ) -> KrpcResult<Stream<'a, T>> {
-        let stream_args = vec![
-            Rpc::create_procedure_call(service, procedure, args).encode()?,
-            false.encode()?,
-        ];
+        self.add_stream_for_call(Rpc::create_procedure_call(service, procedure, args))
+    }
+
+    /// Adds a stream for a procedure where `None` arguments are omitted, so that the server
+    /// uses the parameter's default value instead.
+    pub fn add_stream_with_defaults<'a, T: Decode<'a>>(
+        &'a self,
+        service: &str,
+        procedure: &str,
+        args: &[Option<Vec<u8>>],
+    ) -> KrpcResult<Stream<'a, T>> {
+        self.add_stream_for_call(Rpc::create_procedure_call_with_defaults(service, procedure, args))
+    }
+
+    /// Adds a stream for a `ProcedureCall`, such as one returned by the `*Call` companion of a
+    /// service or remote object.
+    pub fn add_stream_for_call<'a, T: Decode<'a>>(
+        &'a self,
+        procedure_call: ProcedureCall,
+    ) -> KrpcResult<Stream<'a, T>> {
+        let stream_args = vec![procedure_call.encode()?, false.encode()?];
         let response = self.rpc.invoke("KRPC", "AddStream", &stream_args)?;
 
         let stream = schema::Stream::decode(&response, self)?;
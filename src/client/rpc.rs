@@ -7,6 +7,7 @@ use super::{
 };
 
 use failure::Error;
+use protobuf::RepeatedField;
 use std::cell::RefCell;
 use std::net::TcpStream;
 
@@ -52,30 +53,43 @@ impl Rpc {
         procedure: &str,
         args: &Vec<Vec<u8>>,
     ) -> KrpcResult<Vec<u8>> {
-        let request = Self::create_request(service, procedure, args);
-
-        send_msg(&mut self.socket.borrow_mut(), &request)?;
-        let response: Response = recv_msg(&mut self.socket.borrow_mut())?;
+        self.invoke_procedure_call(Self::create_procedure_call(service, procedure, args))
+    }
 
-        if response.has_error() {
-            Err(Error::from(ResponseError::from(response.get_error())))
-        } else {
-            let results = response.get_results();
-            if results.len() == 0 {
-                Err(Error::from(ResponseError::MissingResult))
-            } else {
-                Ok(convert_procedure_result(&results[0])?)
-            }
+    pub(super) fn invoke_procedure_call(&self, procedure_call: ProcedureCall) -> KrpcResult<Vec<u8>> {
+        match self.invoke_batch(vec![procedure_call])?.into_iter().next() {
+            Some(result) => Ok(result?),
+            None => Err(Error::from(ResponseError::MissingResult)),
         }
     }
 
-    pub(super) fn create_request(service: &str, procedure: &str, args: &[Vec<u8>]) -> Request {
+    /// Sends all of the `calls` in a single request. The results are in the same order as the
+    /// calls, and a call that failed does not prevent the others from returning their value.
+    pub(super) fn invoke_batch(
+        &self,
+        calls: Vec<ProcedureCall>,
+    ) -> KrpcResult<Vec<Result<Vec<u8>, ResponseError>>> {
+        let expected = calls.len();
         let mut request = Request::new();
-        request
-            .calls
-            .push(Self::create_procedure_call(service, procedure, args));
+        request.set_calls(RepeatedField::from_vec(calls));
 
-        request
+        send_msg(&mut self.socket.borrow_mut(), &request)?;
+        let mut response: Response = recv_msg(&mut self.socket.borrow_mut())?;
+
+        if response.has_error() {
+            return Err(Error::from(ResponseError::from(response.get_error())));
+        }
+
+        let results: Vec<_> = response
+            .take_results()
+            .into_iter()
+            .map(convert_procedure_result)
+            .collect();
+        if results.len() < expected {
+            Err(Error::from(ResponseError::MissingResult))
+        } else {
+            Ok(results)
+        }
     }
 
     pub(super) fn create_procedure_call(
@@ -97,4 +111,28 @@ impl Rpc {
 
         procedure_call
     }
+
+    /// Creates a procedure call where `None` arguments are omitted, so that the server
+    /// uses the parameter's default value. The remaining arguments keep their positions.
+    pub(super) fn create_procedure_call_with_defaults(
+        service: &str,
+        procedure: &str,
+        args: &[Option<Vec<u8>>],
+    ) -> ProcedureCall {
+        let mut procedure_call = ProcedureCall::new();
+        procedure_call.set_service(service.to_owned());
+        procedure_call.set_procedure(procedure.to_owned());
+
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(arg) = arg {
+                let mut rpc_arg = Argument::new();
+                rpc_arg.set_position(i as u32);
+                rpc_arg.set_value(arg.clone());
+
+                procedure_call.arguments.push(rpc_arg);
+            }
+        }
+
+        procedure_call
+    }
 }
@@ -6,17 +6,21 @@ use super::{
     convert_procedure_result, recv_msg, send_msg, Connection, ConnectionError, KrpcResult,
     ResponseError, StreamError,
 };
-use crate::codec::{Decode, Encode};
+use crate::codec::{Bytes, Decode, DecodeRef, Encode};
 
 use std::io;
 use std::thread;
 
 use failure::Error;
 use std::collections::BTreeMap;
+use std::future::Future;
 use std::marker::PhantomData;
+use std::mem;
 use std::net::TcpStream;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
 use std::time::{Duration, Instant};
 
 /// An event is a KRPC object that allows clients to wait for an Event to occur.  This is
@@ -139,6 +143,22 @@ impl<'a, T: Decode<'a>> Stream<'a, T> {
             .value_map(|bytes, _version| Ok(T::decode(bytes, self.connection)?))
     }
 
+    /// Calls `f` with the encoded value of the stream, without copying or decoding it. If the
+    /// stream is not started, this will start it and wait for the stream to be updated.
+    ///
+    /// The stream is locked while `f` runs, so `f` should not block.
+    pub fn with_raw_value<F, R>(&self, f: F) -> KrpcResult<R>
+    where
+        F: FnOnce(&[u8]) -> KrpcResult<R>,
+    {
+        if !self.value.is_started() {
+            self.start()?;
+            self.wait()?;
+        }
+
+        self.value.value_map(|bytes, _version| f(bytes))
+    }
+
     /// Returns whether or not the stream has started.
     pub fn is_started(&self) -> bool {
         self.value.is_started()
@@ -231,6 +251,59 @@ impl<'a, T: Decode<'a>> Stream<'a, T> {
 
         self.value.wait_timeout(timeout)
     }
+
+    /// Returns a future that resolves when the stream value is next updated. Unlike `wait()`,
+    /// it does not block: the task is woken by the thread that receives the stream updates.
+    pub fn wait_async(&self) -> StreamWait<'_> {
+        StreamWait {
+            value: &self.value,
+            version: if self.removed {
+                Err(StreamError::Removed)
+            } else {
+                self.value.version()
+            },
+        }
+    }
+}
+
+/// A future that resolves when a stream value is next updated. See `Stream::wait_async()`.
+pub struct StreamWait<'s> {
+    value: &'s StreamRaw,
+    /// The version of the value when the future was created.
+    version: Result<u64, StreamError>,
+}
+
+impl<'s> Future for StreamWait<'s> {
+    type Output = KrpcResult<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        match self.version {
+            Ok(version) => self.value.poll_update(version, cx.waker()),
+            Err(ref e) => Poll::Ready(Err(Error::from(e.clone()))),
+        }
+    }
+}
+
+impl<'a> Stream<'a, String> {
+    /// Calls `f` with the current value of the stream, borrowed instead of copied into a new
+    /// `String`. See `with_raw_value()`.
+    pub fn with_str<F, R>(&self, f: F) -> KrpcResult<R>
+    where
+        F: FnOnce(&str) -> R,
+    {
+        self.with_raw_value(|bytes| Ok(f(<&str>::decode_ref(bytes)?)))
+    }
+}
+
+impl<'a> Stream<'a, Bytes> {
+    /// Calls `f` with the current value of the stream, borrowed instead of copied into a new
+    /// `Bytes`. See `with_raw_value()`.
+    pub fn with_bytes<F, R>(&self, f: F) -> KrpcResult<R>
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        self.with_raw_value(|bytes| Ok(f(<&[u8]>::decode_ref(bytes)?)))
+    }
 }
 
 impl<'a, T: Decode<'a>> Drop for Stream<'a, T> {
@@ -309,9 +382,34 @@ impl StreamRaw {
         self.id
     }
 
+    fn version(&self) -> Result<u64, StreamError> {
+        let state = self.state.lock().unwrap();
+
+        if !state.started {
+            return Err(StreamError::NotStarted);
+        }
+
+        Ok(state.version)
+    }
+
+    /// Returns whether the value has been updated since `version`, and if not, registers
+    /// `waker` to be woken on the next update.
+    fn poll_update(&self, version: u64, waker: &Waker) -> Poll<KrpcResult<()>> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.version > version {
+            return Poll::Ready(Ok(()));
+        }
+
+        if !state.wakers.iter().any(|w| w.will_wake(waker)) {
+            state.wakers.push(waker.clone());
+        }
+        Poll::Pending
+    }
+
     fn value_map<F, R>(&self, map: F) -> KrpcResult<R>
     where
-        F: FnOnce(&Vec<u8>, u64) -> KrpcResult<R>,
+        F: FnOnce(&[u8], u64) -> KrpcResult<R>,
     {
         let state = self.state.lock().unwrap();
 
@@ -336,21 +434,28 @@ impl StreamRaw {
     }
 
     fn set_value(&self, bytes: Vec<u8>) {
-        let mut state = self.state.lock().unwrap();
-
-        state.version += 1;
-        state.value = Ok(bytes);
-
-        self.update_cvar.notify_all();
+        self.update(Ok(bytes));
     }
 
     fn set_error(&self, err: ResponseError) {
-        let mut state = self.state.lock().unwrap();
+        self.update(Err(err));
+    }
 
-        state.version += 1;
-        state.value = Err(err);
+    fn update(&self, value: Result<Vec<u8>, ResponseError>) {
+        let wakers = {
+            let mut state = self.state.lock().unwrap();
 
-        self.update_cvar.notify_all();
+            state.version += 1;
+            state.value = value;
+
+            self.update_cvar.notify_all();
+            mem::take(&mut state.wakers)
+        };
+
+        // Woken outside of the lock, in case a task is polled straight away.
+        for waker in wakers {
+            waker.wake();
+        }
     }
 
     fn set_rate(&self, rate: f32) {
@@ -371,6 +476,8 @@ struct StreamState {
     version: u64,
     rate: f32,
     value: Result<Vec<u8>, ResponseError>,
+    /// The tasks waiting for the next update.
+    wakers: Vec<Waker>,
 }
 
 impl StreamState {
@@ -380,6 +487,7 @@ impl StreamState {
             version: 0,
             rate: 0.0,
             value: Err(ResponseError::MissingResult),
+            wakers: Vec::new(),
         }
     }
 }
@@ -447,15 +555,17 @@ impl StreamManager {
     }
 
     fn process_stream_update(
-        stream_update: StreamUpdate,
+        mut stream_update: StreamUpdate,
         active_streams: &Mutex<BTreeMap<u64, Arc<StreamRaw>>>,
     ) {
         let streams = active_streams.lock().unwrap();
 
-        for stream_result in stream_update.results.iter() {
+        // The results are moved out of the update so each value is handed to its stream
+        // without being copied.
+        for mut stream_result in stream_update.take_results().into_iter() {
             if let Some(stream_value) = streams.get(&stream_result.id) {
                 if stream_result.has_result() {
-                    match convert_procedure_result(stream_result.get_result()) {
+                    match convert_procedure_result(stream_result.take_result()) {
                         Ok(bytes) => stream_value.set_value(bytes),
                         Err(response_err) => stream_value.set_error(response_err),
                     }
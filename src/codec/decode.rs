@@ -1,81 +1,153 @@
-use super::{CodecError, CodecResult};
-use crate::client::schema::{Dictionary, Event, List, Services, Set, Status, Stream, Tuple};
+use super::{Bytes, CodecError, CodecResult, Error, ProcedureCodecError, ProcedureValue};
+use crate::client::schema::{Event, Services, Status, Stream};
 use crate::client::Connection;
 
-use protobuf::{CodedInputStream, Message};
+use protobuf::wire_format::WireType;
+use protobuf::{CodedInputStream, Message, ProtobufResult};
 
+use std::any::type_name;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
-use std::hash::Hash;
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::hash::{BuildHasher, Hash};
 
 pub trait Decode<'a>
 where
     Self: 'a + Sized,
 {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self>;
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self>;
+}
+
+/// Decodes a value that borrows from the encoded bytes instead of copying them.
+///
+/// This is implemented for `&str`, the borrowed form of `String`, and `&[u8]`, the borrowed
+/// form of `Bytes`.
+pub trait DecodeRef<'b>
+where
+    Self: 'b + Sized,
+{
+    fn decode_ref(bytes: &'b [u8]) -> CodecResult<Self>;
+}
+
+impl<'b> DecodeRef<'b> for &'b str {
+    fn decode_ref(bytes: &'b [u8]) -> CodecResult<Self> {
+        let value = length_delimited::<Self>(bytes)?;
+        std::str::from_utf8(value).map_err(malformed::<Self, _>)
+    }
+}
+
+impl<'b> DecodeRef<'b> for &'b [u8] {
+    fn decode_ref(bytes: &'b [u8]) -> CodecResult<Self> {
+        length_delimited::<Self>(bytes)
+    }
+}
+
+/// Decodes the return value of a remote procedure call. Any error is wrapped in a
+/// `ProcedureCodecError` naming the procedure and the expected type.
+pub fn decode_result<'a, T: Decode<'a>>(
+    bytes: &[u8],
+    connection: &'a Connection,
+    service: &'static str,
+    procedure: &'static str,
+) -> CodecResult<T> {
+    T::decode(bytes, connection).map_err(|err| {
+        ProcedureCodecError::new::<T>(service, procedure, ProcedureValue::ReturnValue, err).into()
+    })
 }
 
 impl<'a> Decode<'a> for bool {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_bool()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_bool())
     }
 }
 
 impl<'a> Decode<'a> for i32 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_sint32()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_sint32())
     }
 }
 
 impl<'a> Decode<'a> for i64 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_sint64()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_sint64())
+    }
+}
+
+impl<'a> Decode<'a> for u8 {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        let value: u32 = decode_with(bytes, |cis| cis.read_uint32())?;
+        u8::try_from(value).map_err(|_| {
+            Error::from(CodecError::ValueOutOfRange {
+                value: u64::from(value),
+                type_name: "u8",
+            })
+        })
     }
 }
 
 impl<'a> Decode<'a> for u32 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_uint32()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_uint32())
     }
 }
 
 impl<'a> Decode<'a> for u64 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_uint64()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_uint64())
     }
 }
 
 impl<'a> Decode<'a> for f32 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_float()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_float())
     }
 }
 
 impl<'a> Decode<'a> for f64 {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_double()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_double())
     }
 }
 
 impl<'a> Decode<'a> for String {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_string()?))
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_string())
     }
 }
 
-impl<'a> Decode<'a> for Vec<u8> {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        decode_with(bytes, |cis| Ok(cis.read_bytes()?))
+impl<'a> Decode<'a> for Bytes {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_with(bytes, |cis| cis.read_bytes().map(Bytes))
     }
 }
 
+// A null value is encoded as an empty value. Remote objects use their own `Option` decoding,
+// where null is the id 0.
+macro_rules! option_impls {
+    ($( $ty:ty ),+) => {
+        $(
+            impl<'a> Decode<'a> for Option<$ty> {
+                fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+                    if bytes.is_empty() {
+                        Ok(None)
+                    } else {
+                        Ok(Some(<$ty>::decode(bytes, connection)?))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+option_impls!(bool, i32, i64, u8, u32, u64, f32, f64, String, Bytes);
+
 impl<'a, T: Decode<'a>> Decode<'a> for Vec<T> {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut list = List::new();
-        list.merge_from_bytes(bytes.as_slice())?;
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        let items = repeated_bytes::<Self>(bytes, LIST_ITEMS)?;
 
-        let mut decoded_vec = Vec::with_capacity(list.items.len());
+        let mut decoded_vec = Vec::with_capacity(items.len());
 
-        for entry in list.items.iter() {
+        for entry in items {
             decoded_vec.push(T::decode(entry, connection)?);
         }
 
@@ -84,44 +156,44 @@ impl<'a, T: Decode<'a>> Decode<'a> for Vec<T> {
 }
 
 impl<'a, K: Decode<'a> + Ord, V: Decode<'a>> Decode<'a> for BTreeMap<K, V> {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut dict = Dictionary::new();
-        dict.merge_from_bytes(bytes.as_slice())?;
-
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
         let mut decoded_map = BTreeMap::new();
-        for entry in dict.entries.iter() {
-            decoded_map.insert(
-                K::decode(&entry.key, connection)?,
-                V::decode(&entry.value, connection)?,
-            );
+        for entry in repeated_bytes::<Self>(bytes, DICTIONARY_ENTRIES)? {
+            let (key, value) = dictionary_entry::<Self>(entry)?;
+            decoded_map.insert(K::decode(key, connection)?, V::decode(value, connection)?);
         }
         Ok(decoded_map)
     }
 }
 
-impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>> Decode<'a> for HashMap<K, V> {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut dict = Dictionary::new();
-        dict.merge_from_bytes(bytes.as_slice())?;
+impl<'a, K, V, S> Decode<'a> for HashMap<K, V, S>
+where
+    K: Decode<'a> + Eq + Hash,
+    V: Decode<'a>,
+    S: BuildHasher + Default + 'a,
+{
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        let entries = repeated_bytes::<Self>(bytes, DICTIONARY_ENTRIES)?;
 
-        let mut decoded_map = HashMap::with_capacity(dict.entries.len());
-        for entry in dict.entries.iter() {
-            decoded_map.insert(
-                K::decode(&entry.key, connection)?,
-                V::decode(&entry.value, connection)?,
-            );
+        let mut decoded_map = HashMap::with_capacity_and_hasher(entries.len(), S::default());
+        for entry in entries {
+            let (key, value) = dictionary_entry::<Self>(entry)?;
+            decoded_map.insert(K::decode(key, connection)?, V::decode(value, connection)?);
         }
         Ok(decoded_map)
     }
 }
 
-impl<'a, T: Decode<'a> + Eq + Hash> Decode<'a> for HashSet<T> {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut set = Set::new();
-        set.merge_from_bytes(bytes.as_slice())?;
+impl<'a, T, S> Decode<'a> for HashSet<T, S>
+where
+    T: Decode<'a> + Eq + Hash,
+    S: BuildHasher + Default + 'a,
+{
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        let items = repeated_bytes::<Self>(bytes, SET_ITEMS)?;
 
-        let mut decoded_set = HashSet::with_capacity(set.items.len());
-        for entry in set.items.iter() {
+        let mut decoded_set = HashSet::with_capacity_and_hasher(items.len(), S::default());
+        for entry in items {
             decoded_set.insert(T::decode(entry, connection)?);
         }
         Ok(decoded_set)
@@ -129,12 +201,9 @@ impl<'a, T: Decode<'a> + Eq + Hash> Decode<'a> for HashSet<T> {
 }
 
 impl<'a, T: Decode<'a> + Ord> Decode<'a> for BTreeSet<T> {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut set = Set::new();
-        set.merge_from_bytes(bytes.as_slice())?;
-
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
         let mut decoded_set = BTreeSet::new();
-        for entry in set.items.iter() {
+        for entry in repeated_bytes::<Self>(bytes, SET_ITEMS)? {
             decoded_set.insert(T::decode(entry, connection)?);
         }
         Ok(decoded_set)
@@ -142,124 +211,202 @@ impl<'a, T: Decode<'a> + Ord> Decode<'a> for BTreeSet<T> {
 }
 
 impl<'a> Decode<'a> for () {
-    fn decode(_bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
+    fn decode(_bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
         Ok(())
     }
 }
 
-impl<'a, T1: Decode<'a>> Decode<'a> for (T1,) {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut tuple = Tuple::new();
-        tuple.merge_from_bytes(bytes.as_slice())?;
+macro_rules! tuple_impls {
+    ($( $len:expr => ( $( $name:ident $index:tt ),+ ) )+) => {
+        $(
+            impl<'a, $( $name: Decode<'a> ),+> Decode<'a> for ($( $name, )+) {
+                fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+                    let items = decode_tuple::<Self>(bytes, $len)?;
+                    Ok(($( $name::decode(items[$index], connection)?, )+))
+                }
+            }
+        )+
+    };
+}
+
+tuple_impls! {
+    1 => (T1 0)
+    2 => (T1 0, T2 1)
+    3 => (T1 0, T2 1, T3 2)
+    4 => (T1 0, T2 1, T3 2, T4 3)
+    5 => (T1 0, T2 1, T3 2, T4 3, T5 4)
+    6 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5)
+    7 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6)
+    8 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7)
+    9 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8)
+    10 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9)
+    11 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10)
+    12 => (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10, T12 11)
+}
+
+/// Fixed-size arrays are decoded from a `Tuple` with exactly `N` items.
+impl<'a, T: Decode<'a>, const N: usize> Decode<'a> for [T; N] {
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        let items = decode_tuple::<Self>(bytes, N)?;
 
-        if tuple.items.len() != 1 {
-            Err(CodecError::MismatchedTupleLength {
-                actual: tuple.items.len(),
-                expected: 1,
-            })?;
+        let mut decoded_vec = Vec::with_capacity(N);
+        for entry in items {
+            decoded_vec.push(T::decode(entry, connection)?);
         }
 
-        Ok((T1::decode(&tuple.get_items()[0], connection)?,))
+        <[T; N]>::try_from(decoded_vec).map_err(|items| {
+            Error::from(CodecError::MismatchedTupleLength {
+                type_name: type_name::<Self>(),
+                actual: items.len(),
+                expected: N,
+            })
+        })
     }
 }
 
-impl<'a, T1: Decode<'a>, T2: Decode<'a>> Decode<'a> for (T1, T2) {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut tuple = Tuple::new();
-        tuple.merge_from_bytes(bytes.as_slice())?;
-
-        if tuple.items.len() != 2 {
-            Err(CodecError::MismatchedTupleLength {
-                actual: tuple.items.len(),
-                expected: 2,
-            })?;
-        }
+impl<'a> Decode<'a> for Services {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_message(bytes)
+    }
+}
 
-        Ok((
-            T1::decode(&tuple.get_items()[0], connection)?,
-            T2::decode(&tuple.get_items()[1], connection)?,
-        ))
+impl<'a> Decode<'a> for Status {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_message(bytes)
     }
 }
 
-impl<'a, T1: Decode<'a>, T2: Decode<'a>, T3: Decode<'a>> Decode<'a> for (T1, T2, T3) {
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut tuple = Tuple::new();
-        tuple.merge_from_bytes(bytes.as_slice())?;
+impl<'a> Decode<'a> for Stream {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_message(bytes)
+    }
+}
 
-        if tuple.items.len() != 3 {
-            Err(CodecError::MismatchedTupleLength {
-                actual: tuple.items.len(),
-                expected: 3,
-            })?;
-        }
+impl<'a> Decode<'a> for Event {
+    fn decode(bytes: &[u8], _connection: &'a Connection) -> CodecResult<Self> {
+        decode_message(bytes)
+    }
+}
 
-        Ok((
-            T1::decode(&tuple.get_items()[0], connection)?,
-            T2::decode(&tuple.get_items()[1], connection)?,
-            T3::decode(&tuple.get_items()[2], connection)?,
-        ))
+/// Decodes a `Tuple` message into the encoded values of its items, checking that it has
+/// exactly `expected` items. `T` is the type being decoded, and is named in any error. Used by
+/// the tuple and `#[derive(Decode)]` implementations.
+pub fn decode_tuple<T: ?Sized>(bytes: &[u8], expected: usize) -> CodecResult<Vec<&[u8]>> {
+    let items = repeated_bytes::<T>(bytes, TUPLE_ITEMS)?;
+
+    if items.len() != expected {
+        Err(CodecError::MismatchedTupleLength {
+            type_name: type_name::<T>(),
+            actual: items.len(),
+            expected,
+        })?;
     }
+
+    Ok(items)
 }
 
-impl<'a, T1: Decode<'a>, T2: Decode<'a>, T3: Decode<'a>, T4: Decode<'a>> Decode<'a>
-    for (T1, T2, T3, T4)
-{
-    fn decode(bytes: &Vec<u8>, connection: &'a Connection) -> CodecResult<Self> {
-        let mut tuple = Tuple::new();
-        tuple.merge_from_bytes(bytes.as_slice())?;
-
-        if tuple.items.len() != 4 {
-            Err(CodecError::MismatchedTupleLength {
-                actual: tuple.items.len(),
-                expected: 4,
-            })?;
+// Field numbers of the collection messages in `krpc.proto`.
+const LIST_ITEMS: u32 = 1;
+const SET_ITEMS: u32 = 1;
+const TUPLE_ITEMS: u32 = 1;
+const DICTIONARY_ENTRIES: u32 = 1;
+const DICTIONARY_ENTRY_KEY: u32 = 1;
+const DICTIONARY_ENTRY_VALUE: u32 = 2;
+
+/// Returns every value of the length-delimited `field` in the encoded message, in order.
+/// The values borrow from `bytes`, so collections can be decoded without copying each item
+/// into its own message first. Other fields are skipped.
+fn repeated_bytes<T: ?Sized>(bytes: &[u8], field: u32) -> CodecResult<Vec<&[u8]>> {
+    let mut values = Vec::new();
+    read_fields::<T, _>(bytes, |number, value| {
+        if number == field {
+            values.push(value);
         }
+    })?;
 
-        Ok((
-            T1::decode(&tuple.get_items()[0], connection)?,
-            T2::decode(&tuple.get_items()[1], connection)?,
-            T3::decode(&tuple.get_items()[2], connection)?,
-            T4::decode(&tuple.get_items()[3], connection)?,
-        ))
-    }
+    Ok(values)
 }
 
-impl<'a> Decode<'a> for Services {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        let mut value = Self::new();
-        value.merge_from_bytes(bytes.as_slice())?;
-        Ok(value)
-    }
+/// Returns the encoded key and value of a `DictionaryEntry`. A missing field is an empty
+/// value, as it is for any proto3 message.
+fn dictionary_entry<T: ?Sized>(bytes: &[u8]) -> CodecResult<(&[u8], &[u8])> {
+    let mut key: &[u8] = &[];
+    let mut value: &[u8] = &[];
+    read_fields::<T, _>(bytes, |number, field| match number {
+        DICTIONARY_ENTRY_KEY => key = field,
+        DICTIONARY_ENTRY_VALUE => value = field,
+        _ => (),
+    })?;
+
+    Ok((key, value))
 }
 
-impl<'a> Decode<'a> for Status {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        let mut value = Self::new();
-        value.merge_from_bytes(bytes.as_slice())?;
-        Ok(value)
-    }
+/// Calls `f` with the number and value of each length-delimited field in the encoded message.
+/// Fields of other wire types are skipped. Every length is checked against the input, so a
+/// truncated message is an error instead of a panic.
+fn read_fields<'b, T: ?Sized, F>(bytes: &'b [u8], mut f: F) -> CodecResult<()>
+where
+    F: FnMut(u32, &'b [u8]),
+{
+    let mut cis = CodedInputStream::from_bytes(bytes);
+    let result: ProtobufResult<()> = (|| {
+        while !cis.eof()? {
+            let (number, wire_type) = cis.read_tag_unpack()?;
+            if wire_type == WireType::WireTypeLengthDelimited {
+                let len = cis.read_raw_varint32()?;
+                let start = cis.pos() as usize;
+                cis.skip_raw_bytes(len)?;
+                f(number, &bytes[start..start + len as usize]);
+            } else {
+                cis.skip_field(wire_type)?;
+            }
+        }
+        Ok(())
+    })();
+
+    result.map_err(malformed::<T, _>)
 }
 
-impl<'a> Decode<'a> for Stream {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        let mut value = Self::new();
-        value.merge_from_bytes(bytes.as_slice())?;
-        Ok(value)
+/// Returns the contents of a length-delimited value, such as a string or bytes.
+fn length_delimited<T: ?Sized>(bytes: &[u8]) -> CodecResult<&[u8]> {
+    let mut cis = CodedInputStream::from_bytes(bytes);
+    let result: ProtobufResult<usize> = (|| {
+        let len = cis.read_raw_varint32()?;
+        cis.skip_raw_bytes(len)?;
+        Ok(len as usize)
+    })();
+    let len = result.map_err(malformed::<T, _>)?;
+
+    let end = cis.pos() as usize;
+    if end != bytes.len() {
+        return Err(malformed::<T, _>("unexpected trailing bytes"));
     }
+    Ok(&bytes[end - len..end])
 }
-impl<'a> Decode<'a> for Event {
-    fn decode(bytes: &Vec<u8>, _connection: &'a Connection) -> CodecResult<Self> {
-        let mut value = Self::new();
-        value.merge_from_bytes(bytes.as_slice())?;
-        Ok(value)
-    }
+
+fn decode_message<M: Message>(bytes: &[u8]) -> CodecResult<M> {
+    let mut value = M::new();
+    value.merge_from_bytes(bytes).map_err(malformed::<M, _>)?;
+    Ok(value)
 }
 
-fn decode_with<T, F>(bytes: &Vec<u8>, decoder: F) -> CodecResult<T>
+/// Decodes a single value with `decoder`, checking that it uses all of `bytes`.
+fn decode_with<T, F>(bytes: &[u8], decoder: F) -> CodecResult<T>
 where
-    F: FnOnce(&mut CodedInputStream) -> CodecResult<T>,
+    F: FnOnce(&mut CodedInputStream) -> ProtobufResult<T>,
 {
     let mut cis = CodedInputStream::from_bytes(bytes);
-    decoder(&mut cis)
+    let value = decoder(&mut cis).map_err(malformed::<T, _>)?;
+
+    if !cis.eof().map_err(malformed::<T, _>)? {
+        return Err(malformed::<T, _>("unexpected trailing bytes"));
+    }
+    Ok(value)
+}
+
+fn malformed<T: ?Sized, R: Display>(reason: R) -> Error {
+    Error::from(CodecError::Malformed {
+        type_name: type_name::<T>(),
+        reason: reason.to_string(),
+    })
 }
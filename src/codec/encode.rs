@@ -1,15 +1,21 @@
-use super::CodecResult;
+use super::{Bytes, CodecResult, ProcedureCodecError, ProcedureValue};
 use crate::client::schema::{Dictionary, DictionaryEntry, List, ProcedureCall, Set, Tuple};
 
-use protobuf::{CodedOutputStream, Message};
+use protobuf::{CodedOutputStream, Message, RepeatedField};
 
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 pub trait Encode {
     fn encode(&self) -> CodecResult<Vec<u8>>;
 }
 
+impl<T: Encode + ?Sized> Encode for &T {
+    fn encode(&self) -> CodecResult<Vec<u8>> {
+        (**self).encode()
+    }
+}
+
 impl Encode for bool {
     fn encode(&self) -> CodecResult<Vec<u8>> {
         encode_with(|cos| Ok(cos.write_bool_no_tag(*self)?))
@@ -28,6 +34,12 @@ impl Encode for i64 {
     }
 }
 
+impl Encode for u8 {
+    fn encode(&self) -> CodecResult<Vec<u8>> {
+        encode_with(|cos| Ok(cos.write_uint32_no_tag(u32::from(*self))?))
+    }
+}
+
 impl Encode for u32 {
     fn encode(&self) -> CodecResult<Vec<u8>> {
         encode_with(|cos| Ok(cos.write_uint32_no_tag(*self)?))
@@ -64,22 +76,49 @@ impl Encode for &str {
     }
 }
 
-impl Encode for Vec<u8> {
+impl Encode for Bytes {
     fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| Ok(cos.write_bytes_no_tag(self.as_slice())?))
+        encode_with(|cos| Ok(cos.write_bytes_no_tag(self.as_ref())?))
     }
 }
 
+// A null value is encoded as an empty value. Remote objects use their own `Option` encoding,
+// where null is the id 0.
+macro_rules! option_impls {
+    ($( $ty:ty ),+) => {
+        $(
+            impl Encode for Option<$ty> {
+                fn encode(&self) -> CodecResult<Vec<u8>> {
+                    match self {
+                        Some(value) => value.encode(),
+                        None => Ok(Vec::new()),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+option_impls!(bool, i32, i64, u8, u32, u64, f32, f64, String, &str, Bytes);
+
 impl Encode for ProcedureCall {
     fn encode(&self) -> CodecResult<Vec<u8>> {
         encode_with(|cos| Ok(self.write_to(cos)?))
     }
 }
 
-impl Encode for &[u8] {
-    fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| Ok(cos.write_bytes_no_tag(self)?))
-    }
+/// Encodes the argument at `position` of a remote procedure call. Any error is wrapped in a
+/// `ProcedureCodecError` naming the procedure, the position and the argument's type.
+pub fn encode_argument<T: Encode + ?Sized>(
+    value: &T,
+    service: &'static str,
+    procedure: &'static str,
+    position: usize,
+) -> CodecResult<Vec<u8>> {
+    value.encode().map_err(|err| {
+        ProcedureCodecError::new::<T>(service, procedure, ProcedureValue::Argument(position), err)
+            .into()
+    })
 }
 
 impl<T: Encode> Encode for Vec<T> {
@@ -128,7 +167,7 @@ impl<K: Encode + Ord, V: Encode> Encode for BTreeMap<K, V> {
     }
 }
 
-impl<K: Encode + Eq + Hash, V: Encode> Encode for HashMap<K, V> {
+impl<K: Encode + Eq + Hash, V: Encode, S: BuildHasher> Encode for HashMap<K, V, S> {
     fn encode(&self) -> CodecResult<Vec<u8>> {
         encode_with(|cos| {
             let mut dict = Dictionary::new();
@@ -146,7 +185,7 @@ impl<K: Encode + Eq + Hash, V: Encode> Encode for HashMap<K, V> {
     }
 }
 
-impl<T: Encode + Eq + Hash> Encode for HashSet<T> {
+impl<T: Encode + Eq + Hash, S: BuildHasher> Encode for HashSet<T, S> {
     fn encode(&self) -> CodecResult<Vec<u8>> {
         encode_with(|cos| {
             let mut set = Set::new();
@@ -176,54 +215,54 @@ impl<T: Encode + Ord> Encode for BTreeSet<T> {
     }
 }
 
-impl<T1: Encode> Encode for (T1,) {
-    fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| {
-            let mut tuple = Tuple::new();
-            tuple.items.push(self.0.encode()?);
-            tuple.write_to(cos)?;
-            Ok(())
-        })
-    }
+macro_rules! tuple_impls {
+    ($( ( $( $name:ident $index:tt ),+ ) )+) => {
+        $(
+            impl<$( $name: Encode ),+> Encode for ($( $name, )+) {
+                fn encode(&self) -> CodecResult<Vec<u8>> {
+                    encode_tuple(vec![$( self.$index.encode()? ),+])
+                }
+            }
+        )+
+    };
 }
 
-impl<T1: Encode, T2: Encode> Encode for (T1, T2) {
-    fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| {
-            let mut tuple = Tuple::new();
-            tuple.items.push(self.0.encode()?);
-            tuple.items.push(self.1.encode()?);
-            tuple.write_to(cos)?;
-            Ok(())
-        })
-    }
+tuple_impls! {
+    (T1 0)
+    (T1 0, T2 1)
+    (T1 0, T2 1, T3 2)
+    (T1 0, T2 1, T3 2, T4 3)
+    (T1 0, T2 1, T3 2, T4 3, T5 4)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10)
+    (T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10, T12 11)
 }
 
-impl<T1: Encode, T2: Encode, T3: Encode> Encode for (T1, T2, T3) {
+/// Fixed-size arrays are encoded as a `Tuple`, so `[f64; 3]` has the same encoding as a
+/// `Vector3`.
+impl<T: Encode, const N: usize> Encode for [T; N] {
     fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| {
-            let mut tuple = Tuple::new();
-            tuple.items.push(self.0.encode()?);
-            tuple.items.push(self.1.encode()?);
-            tuple.items.push(self.2.encode()?);
-            tuple.write_to(cos)?;
-            Ok(())
-        })
+        let mut items = Vec::with_capacity(N);
+        for item in self.iter() {
+            items.push(item.encode()?);
+        }
+        encode_tuple(items)
     }
 }
 
-impl<T1: Encode, T2: Encode, T3: Encode, T4: Encode> Encode for (T1, T2, T3, T4) {
-    fn encode(&self) -> CodecResult<Vec<u8>> {
-        encode_with(|cos| {
-            let mut tuple = Tuple::new();
-            tuple.items.push(self.0.encode()?);
-            tuple.items.push(self.1.encode()?);
-            tuple.items.push(self.2.encode()?);
-            tuple.items.push(self.3.encode()?);
-            tuple.write_to(cos)?;
-            Ok(())
-        })
-    }
+/// Encodes the already encoded `items` as a `Tuple` message. Used by the tuple and
+/// `#[derive(Encode)]` implementations.
+pub fn encode_tuple(items: Vec<Vec<u8>>) -> CodecResult<Vec<u8>> {
+    encode_with(|cos| {
+        let mut tuple = Tuple::new();
+        tuple.set_items(RepeatedField::from_vec(items));
+        tuple.write_to(cos)?;
+        Ok(())
+    })
 }
 
 fn encode_with<F>(encoder: F) -> CodecResult<Vec<u8>>
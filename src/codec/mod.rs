@@ -5,15 +5,203 @@ pub use crate::codec::decode::*;
 pub use crate::codec::encode::*;
 
 pub use failure::Error;
+pub use krpc_bindings_macros::{Decode, Encode};
 
 #[derive(Debug, Fail)]
 pub enum CodecError {
-    #[fail(display = "Invalid enum value {}", _0)]
-    InvalidEnumValue(i64),
+    #[fail(display = "Invalid value {} for enum {}", value, type_name)]
+    InvalidEnumValue { value: i64, type_name: &'static str },
     #[fail(display = "Value was unexpectedly null")]
     NullValue,
-    #[fail(display = "Expected tuple length {} but was {}", expected, actual)]
-    MismatchedTupleLength { actual: usize, expected: usize },
+    #[fail(
+        display = "Expected tuple length {} for {} but was {}",
+        expected, type_name, actual
+    )]
+    MismatchedTupleLength {
+        actual: usize,
+        expected: usize,
+        type_name: &'static str,
+    },
+    #[fail(display = "Value {} is out of range for {}", value, type_name)]
+    ValueOutOfRange { value: u64, type_name: &'static str },
+    #[fail(display = "Malformed value for {}: {}", type_name, reason)]
+    Malformed {
+        type_name: &'static str,
+        reason: String,
+    },
 }
 
 pub type CodecResult<T> = Result<T, Error>;
+
+/// The value of a remote procedure call that failed to encode or decode.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProcedureValue {
+    /// The argument at the given position. For a remote object method, the object itself is
+    /// the argument at position 0.
+    Argument(usize),
+    /// The value returned by the procedure.
+    ReturnValue,
+}
+
+/// An error encoding an argument of, or decoding the value returned by, a remote procedure.
+///
+/// The generated bindings wrap every codec error in this type, so the log line of a failure
+/// identifies the procedure and the value that could not be converted.
+#[derive(Debug)]
+pub struct ProcedureCodecError {
+    pub service: &'static str,
+    pub procedure: &'static str,
+    pub value: ProcedureValue,
+    /// The Rust type that was being encoded or decoded.
+    pub type_name: &'static str,
+    cause: Error,
+}
+
+impl ProcedureCodecError {
+    pub fn new<T: ?Sized>(
+        service: &'static str,
+        procedure: &'static str,
+        value: ProcedureValue,
+        cause: Error,
+    ) -> Self {
+        ProcedureCodecError {
+            service,
+            procedure,
+            value,
+            type_name: std::any::type_name::<T>(),
+            cause,
+        }
+    }
+
+    /// Returns the underlying codec error.
+    pub fn error(&self) -> &Error {
+        &self.cause
+    }
+}
+
+impl failure::Fail for ProcedureCodecError {
+    fn cause(&self) -> Option<&dyn failure::Fail> {
+        Some(self.cause.as_fail())
+    }
+}
+
+impl std::fmt::Display for ProcedureCodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self.value {
+            ProcedureValue::Argument(position) => write!(
+                f,
+                "Failed to encode argument {} of {}.{} as {}: {}",
+                position, self.service, self.procedure, self.type_name, self.cause
+            ),
+            ProcedureValue::ReturnValue => write!(
+                f,
+                "Failed to decode the return value of {}.{} as {}: {}",
+                self.service, self.procedure, self.type_name, self.cause
+            ),
+        }
+    }
+}
+
+/// A protobuf `bytes` value, such as a client identifier.
+///
+/// `Vec<u8>` is always encoded as a `List` of integers, like any other `Vec<T>`, so raw bytes
+/// are wrapped in this type instead.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Bytes {
+    /// Returns the underlying bytes.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Bytes(Vec::from(bytes))
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Bytes({})", hex::encode(&self.0))
+    }
+}
+
+impl std::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", hex::encode(&self.0))
+    }
+}
+
+// Human-readable formats, such as JSON, get the same hex string as `Display`, and binary
+// formats get the raw bytes.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bytes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(&self.0))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bytes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+            type Value = Bytes;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a hex string or bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Bytes, E> {
+                hex::decode(value).map(Bytes).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Bytes, E> {
+                Ok(Bytes::from(value))
+            }
+
+            fn visit_byte_buf<E: serde::de::Error>(self, value: Vec<u8>) -> Result<Bytes, E> {
+                Ok(Bytes(value))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::Frame;
+use crate::remote_type;
+use crate::spacecenter::Part;
+
+remote_type!(
+    /// A docking camera.
+    object DockingCamera.Camera {
+        properties: {
+            {
+                Part {
+                    /// Returns the part object for this camera.
+                    ///
+                    /// **Game Scenes**: All
+                    get: part -> Part
+                }
+            }
+            {
+                Image {
+                    /// Returns the image currently seen by the camera.
+                    ///
+                    /// **Game Scenes**: All
+                    get: image -> Frame
+                }
+            }
+        }
+    }
+);
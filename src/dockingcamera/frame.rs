@@ -0,0 +1,102 @@
+use crate::client::Connection;
+use crate::codec::{Bytes, CodecError, CodecResult, Decode, Error};
+
+use png::{ColorType, Decoder, Transformations};
+
+/// An image captured by a docking camera, decoded into 8-bit RGBA pixels.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// Returns the width of the image, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the image, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the pixels in row-major order, starting from the top-left corner, with four
+    /// bytes (red, green, blue and alpha) per pixel.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns the red, green, blue and alpha values of the pixel at the given position, or
+    /// `None` if it is outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&self.pixels[offset..offset + 4]);
+        Some(pixel)
+    }
+
+    /// Returns the pixels, as returned by `pixels`.
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// Decodes a PNG image, as sent by the server.
+    pub fn from_png(data: &[u8]) -> CodecResult<Frame> {
+        let mut decoder = Decoder::new(data);
+        decoder.set_transformations(Transformations::EXPAND | Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(malformed)?;
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(malformed)?;
+        buffer.truncate(info.buffer_size());
+
+        // After expansion every sample is 8 bits and there is no palette, so only the number
+        // of channels differs between color types.
+        let pixels = match info.color_type {
+            ColorType::Rgba => buffer,
+            ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], u8::MAX])
+                .collect(),
+            ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+                .collect(),
+            ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, u8::MAX]).collect(),
+            ColorType::Indexed => {
+                return Err(malformed("indexed image was not expanded"));
+            }
+        };
+
+        Ok(Frame {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+}
+
+impl<'a> Decode<'a> for Frame {
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        Frame::from_png(&Bytes::decode(bytes, connection)?)
+    }
+}
+
+impl std::fmt::Debug for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Frame({}x{})", self.width, self.height)
+    }
+}
+
+fn malformed<R: std::fmt::Display>(reason: R) -> Error {
+    Error::from(CodecError::Malformed {
+        type_name: "Frame",
+        reason: reason.to_string(),
+    })
+}
@@ -0,0 +1,37 @@
+use crate::remote_type;
+use crate::spacecenter::Part;
+
+mod camera;
+mod frame;
+
+pub use self::camera::*;
+pub use self::frame::*;
+
+remote_type!(
+    /// This service provides functionality to interact with Docking Camera (KURS).
+    service DockingCamera {
+        properties: {
+            {
+                Available {
+                    /// Returns whether Docking Camera is installed.
+                    ///
+                    /// **Game Scenes**: All
+                    get: is_available -> bool
+                }
+            }
+        }
+        methods: {
+            {
+                /// Get a camera part.
+                ///
+                /// **Game Scenes**: All
+                ///
+                /// # Arguments
+                /// `part` - The part that has a docking camera.
+                fn camera(part: &Part) -> Camera {
+                    Camera(part)
+                }
+            }
+        }
+    }
+);
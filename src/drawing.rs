@@ -1,7 +1,6 @@
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::ReferenceFrame;
 use crate::ui::{FontStyle, TextAlignment, TextAnchor};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// Provides functionality for drawing objects in the flight scene.
@@ -75,6 +74,70 @@ service Drawing {
                 Clear(client_only)
             }
         }
+        {
+            /// Returns a builder for `Drawing::add_line()` whose `visible` argument is optional.
+            /// If it is not set, the line is visible.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `start` – Position of the start of the line.
+            /// * `end` – Position of the end of the line.
+            /// * `reference_frame` – Reference frame that the positions are in.
+            fn add_line_with(start: Vector3, end: Vector3, reference_frame: &ReferenceFrame) [visible: bool] -> Line<'a> {
+                AddLine(start, end, reference_frame)
+            }
+        }
+        {
+            /// Returns a builder for `Drawing::add_direction()` whose `length` and `visible`
+            /// arguments are optional. If they are not set, the line is 10 meters long and visible.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `direction` – Direction to draw the line in.
+            /// * `reference_frame` – Reference frame that the positions are in.
+            fn add_direction_with(direction: Vector3, reference_frame: &ReferenceFrame) [length: f32, visible: bool] -> Line<'a> {
+                AddDirection(direction, reference_frame)
+            }
+        }
+        {
+            /// Returns a builder for `Drawing::add_polygon()` whose `visible` argument is optional.
+            /// If it is not set, the polygon is visible.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `vertices`  – Vertices of the polygon.
+            /// * `reference_frame` – Reference frame that the vertices are in.
+            fn add_polygon_with(vertices: &[Vector3], reference_frame: &ReferenceFrame) [visible: bool] -> Polygon<'a> {
+                AddPolygon(vertices, reference_frame)
+            }
+        }
+        {
+            /// Returns a builder for `Drawing::add_text()` whose `visible` argument is optional.
+            /// If it is not set, the text is visible.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `text`  – The string to draw.
+            /// * `reference_frame` – Reference frame that the text position is in.
+            /// * `position` – Position of the text.
+            /// * `rotation` – Rotation of the text, as a quaternion.
+            fn add_text_with(text: &str, reference_frame: &ReferenceFrame, position: Vector3, rotation: Quaternion) [visible: bool] -> Text<'a> {
+                AddText(text, reference_frame, position, rotation)
+            }
+        }
+        {
+            /// Returns a builder for `Drawing::clear()` whose `client_only` argument is optional.
+            /// If it is not set, objects from all clients are removed.
+            ///
+            /// **Game Scenes**: Flight
+            fn clear_with() [client_only: bool] {
+                Clear()
+            }
+        }
     }
 });
 
@@ -0,0 +1,366 @@
+use super::{BurnPhase, GuidanceError, NodeExecutor};
+use crate::client::{decode_space_center_result, Connection, KrpcResult, Stream};
+use crate::kepler::maneuver::circularize_at_apoapsis;
+use crate::kepler::KeplerOrbit;
+use crate::spacecenter::{AutoPilot, Control, Flight, Node, Orbit, Parts, SpaceCenter, Vessel};
+
+use failure::Error;
+
+/// How much the apoapsis may still rise, in meters, before the throttle is reduced to stop
+/// it overshooting.
+const APOAPSIS_MARGIN: f64 = 1000.0;
+
+/// The lowest throttle used while raising the apoapsis.
+const MIN_THROTTLE: f64 = 0.05;
+
+/// The time to let a stage settle after staging before staging again, in seconds.
+const STAGE_DELAY: f64 = 0.5;
+
+/// The pitch of a gravity turn as a function of altitude.
+///
+/// The vessel climbs vertically until `start_altitude`, then pitches over until it reaches
+/// `final_pitch` at `end_altitude`. The fraction of the turn that is done rises with the
+/// fraction of the altitude climbed raised to `exponent`, so values below 1 turn more quickly
+/// at first.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GravityTurn {
+    /// The altitude at which the turn starts, in meters.
+    pub start_altitude: f64,
+    /// The altitude at which the turn ends, in meters.
+    pub end_altitude: f64,
+    /// How the turn is spread over the climb: 1 pitches over at a steady rate with altitude,
+    /// and smaller values pitch over sooner.
+    pub exponent: f64,
+    /// The pitch at the end of the turn, in degrees above the horizon.
+    pub final_pitch: f64,
+}
+
+impl Default for GravityTurn {
+    /// A turn that suits a small rocket launched from Kerbin.
+    fn default() -> Self {
+        GravityTurn {
+            start_altitude: 1000.0,
+            end_altitude: 45_000.0,
+            exponent: 0.5,
+            final_pitch: 0.0,
+        }
+    }
+}
+
+impl GravityTurn {
+    /// Returns the pitch at the given altitude, in degrees above the horizon.
+    pub fn pitch_at(&self, altitude: f64) -> f64 {
+        let climbed = (altitude - self.start_altitude) / (self.end_altitude - self.start_altitude);
+        let turned = climbed.clamp(0.0, 1.0).powf(self.exponent);
+        90.0 - (90.0 - self.final_pitch) * turned
+    }
+}
+
+/// The phases of an ascent, in order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AscentPhase {
+    /// Throttling up, and staging until there is thrust.
+    Launch,
+    /// Climbing straight up until the start of the gravity turn.
+    VerticalAscent,
+    /// Pitching over while raising the apoapsis to the target altitude.
+    GravityTurn,
+    /// Coasting out of the atmosphere, burning again if drag lowers the apoapsis.
+    Coasting,
+    /// Executing the circularization node at the apoapsis.
+    Circularizing,
+    /// In orbit.
+    Done,
+}
+
+/// Flies a vessel from the launch pad to a circular orbit.
+///
+/// The vessel climbs vertically, follows a gravity turn along `heading` until the apoapsis
+/// reaches the target altitude, coasts out of the atmosphere and then circularizes at the
+/// apoapsis with a `NodeExecutor`. While the engines are burning it stages with
+/// `Control::activate_next_stage` whenever an active engine runs out of fuel or there is no
+/// thrust, unless staging is turned off. If the engines flame out during the circularization
+/// burn, it stages and finishes the burn.
+///
+/// Like `NodeExecutor`, it can be run to completion with `run` or stepped, and both block.
+pub struct Ascent<'a> {
+    connection: &'a Connection,
+    vessel: Vessel<'a>,
+    auto_pilot: AutoPilot<'a>,
+    control: Control<'a>,
+    orbit: Orbit<'a>,
+    parts: Parts<'a>,
+    phase: AscentPhase,
+    node: Option<Node<'a>>,
+    executor: Option<NodeExecutor<'a>>,
+    atmosphere_depth: f64,
+    last_stage: f64,
+    ut: Stream<'a, f64>,
+    altitude: Stream<'a, f64>,
+    apoapsis: Stream<'a, f64>,
+    available_thrust: Stream<'a, f32>,
+    engines_have_fuel: Vec<Stream<'a, bool>>,
+    target_altitude: f64,
+    heading: f64,
+    turn: GravityTurn,
+    auto_stage: bool,
+}
+
+impl<'a> Ascent<'a> {
+    /// Creates an ascent of `vessel` to a circular orbit at `target_altitude`, in meters above
+    /// sea level. Returns `GuidanceError::TargetInAtmosphere` if the target altitude is not
+    /// above the atmosphere, where the orbit would decay.
+    pub fn new(
+        connection: &'a Connection,
+        vessel: &Vessel<'a>,
+        target_altitude: f64,
+    ) -> KrpcResult<Self> {
+        let calls = vessel.call();
+        let batch = vec![
+            calls.auto_pilot()?,
+            calls.control()?,
+            calls.orbit()?,
+            calls.parts()?,
+            calls.flight(None)?,
+        ];
+
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let auto_pilot: AutoPilot<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_AutoPilot")?;
+        let control: Control<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_Control")?;
+        let orbit: Orbit<'a> = decode_space_center_result(results, connection, "Vessel_get_Orbit")?;
+        let parts: Parts<'a> = decode_space_center_result(results, connection, "Vessel_get_Parts")?;
+        let flight: Flight<'a> = decode_space_center_result(results, connection, "Vessel_Flight")?;
+
+        let body = orbit.body()?;
+        let atmosphere_depth = if body.has_atmosphere()? {
+            f64::from(body.atmosphere_depth()?)
+        } else {
+            0.0
+        };
+        // The vessel only circularizes once it has left the atmosphere.
+        if target_altitude <= atmosphere_depth {
+            return Err(Error::from(GuidanceError::TargetInAtmosphere));
+        }
+
+        let mut ascent = Ascent {
+            ut: connection.add_stream_for_call(SpaceCenter::new(connection).call().ut()?)?,
+            altitude: connection.add_stream_for_call(flight.call().mean_altitude()?)?,
+            apoapsis: connection.add_stream_for_call(orbit.call().apoapsis_altitude()?)?,
+            available_thrust: connection.add_stream_for_call(calls.available_thrust()?)?,
+            engines_have_fuel: Vec::new(),
+            connection,
+            vessel: vessel.clone(),
+            auto_pilot,
+            control,
+            orbit,
+            parts,
+            phase: AscentPhase::Launch,
+            node: None,
+            executor: None,
+            atmosphere_depth,
+            last_stage: f64::NEG_INFINITY,
+            target_altitude,
+            heading: 90.0,
+            turn: GravityTurn::default(),
+            auto_stage: true,
+        };
+        ascent.watch_engines()?;
+        Ok(ascent)
+    }
+
+    /// Sets the compass heading to launch along, in degrees. Defaults to 90, due east.
+    pub fn heading(mut self, degrees: f64) -> Self {
+        self.heading = degrees;
+        self
+    }
+
+    /// Sets the profile of the gravity turn. Defaults to `GravityTurn::default()`.
+    pub fn turn(mut self, turn: GravityTurn) -> Self {
+        self.turn = turn;
+        self
+    }
+
+    /// Sets whether to stage automatically. Defaults to `true`.
+    pub fn auto_stage(mut self, auto_stage: bool) -> Self {
+        self.auto_stage = auto_stage;
+        self
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> AscentPhase {
+        self.phase
+    }
+
+    /// Advances the ascent by one step, and returns the phase it is then in.
+    pub fn step(&mut self) -> KrpcResult<AscentPhase> {
+        match self.phase {
+            AscentPhase::Launch => {
+                self.auto_pilot
+                    .target_pitch_and_heading(90.0, self.heading as f32)?;
+                self.auto_pilot.engage()?;
+                self.control.set_throttle(1.0)?;
+                self.stage_if_needed()?;
+                if self.available_thrust.value()? > 0.0 {
+                    self.phase = AscentPhase::VerticalAscent;
+                }
+            }
+            AscentPhase::VerticalAscent => {
+                self.stage_if_needed()?;
+                if self.altitude.value()? >= self.turn.start_altitude {
+                    self.phase = AscentPhase::GravityTurn;
+                }
+            }
+            AscentPhase::GravityTurn => {
+                let pitch = self.turn.pitch_at(self.altitude.value()?);
+                self.auto_pilot
+                    .target_pitch_and_heading(pitch as f32, self.heading as f32)?;
+                if self.raise_apoapsis()? {
+                    self.phase = AscentPhase::Coasting;
+                }
+            }
+            AscentPhase::Coasting => {
+                // Drag keeps lowering the apoapsis until the vessel leaves the atmosphere.
+                self.raise_apoapsis()?;
+                if self.altitude.value()? > self.atmosphere_depth {
+                    self.control.set_throttle(0.0)?;
+                    self.plan_circularization()?;
+                    self.phase = AscentPhase::Circularizing;
+                }
+            }
+            AscentPhase::Circularizing => {
+                if self.executor.is_none() {
+                    // After a flameout, stage until there is thrust to finish the burn with.
+                    if self.available_thrust.value()? <= 0.0 {
+                        self.stage_if_needed()?;
+                        return Ok(self.phase);
+                    }
+                    if let Some(node) = self.node.as_ref() {
+                        self.executor =
+                            Some(NodeExecutor::new(self.connection, &self.vessel, node)?);
+                    }
+                }
+                let step = match self.executor.as_mut() {
+                    Some(executor) => executor.step(),
+                    None => return Ok(self.phase),
+                };
+                match step {
+                    Ok(BurnPhase::Done) => {
+                        self.node = None;
+                        self.executor = None;
+                        self.phase = AscentPhase::Done;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        // The executor leaves the node in place on a flameout, so that the burn
+                        // can be finished after staging.
+                        let flamed_out = matches!(
+                            e.downcast_ref::<GuidanceError>(),
+                            Some(GuidanceError::Flameout)
+                        );
+                        if !flamed_out || !self.auto_stage {
+                            return Err(e);
+                        }
+                        self.executor = None;
+                        self.stage_if_needed()?;
+                    }
+                }
+            }
+            AscentPhase::Done => {}
+        }
+        Ok(self.phase)
+    }
+
+    /// Runs the ascent to completion, blocking until the vessel is in orbit.
+    pub fn run(&mut self) -> KrpcResult<()> {
+        while self.step()? != AscentPhase::Done {
+            self.ut.wait()?;
+        }
+        Ok(())
+    }
+
+    /// Burns towards the target apoapsis, throttling down as it gets close. Returns `true`,
+    /// with the throttle cut, once the apoapsis is high enough.
+    fn raise_apoapsis(&mut self) -> KrpcResult<bool> {
+        let missing = self.target_altitude - self.apoapsis.value()?;
+        if missing <= 0.0 {
+            self.control.set_throttle(0.0)?;
+            return Ok(true);
+        }
+        let throttle = (missing / APOAPSIS_MARGIN).clamp(MIN_THROTTLE, 1.0);
+        self.control.set_throttle(throttle as f32)?;
+        self.stage_if_needed()?;
+        Ok(false)
+    }
+
+    /// Stages if an active engine has run out of fuel, or if there is no thrust.
+    fn stage_if_needed(&mut self) -> KrpcResult<()> {
+        if !self.auto_stage {
+            return Ok(());
+        }
+        let ut = self.ut.value()?;
+        if ut - self.last_stage < STAGE_DELAY {
+            return Ok(());
+        }
+
+        let mut flamed_out = self.available_thrust.value()? <= 0.0;
+        for has_fuel in self.engines_have_fuel.iter() {
+            flamed_out |= !has_fuel.value()?;
+        }
+        if flamed_out && self.control.current_stage()? > 0 {
+            self.control.activate_next_stage()?;
+            self.last_stage = ut;
+            self.watch_engines()?;
+        }
+        Ok(())
+    }
+
+    /// Streams whether each active engine has fuel.
+    fn watch_engines(&mut self) -> KrpcResult<()> {
+        let mut streams = Vec::new();
+        for engine in self.parts.engines()? {
+            if engine.is_active()? {
+                streams.push(
+                    self.connection
+                        .add_stream_for_call(engine.call().has_fuel()?)?,
+                );
+            }
+        }
+        self.engines_have_fuel = streams;
+        Ok(())
+    }
+
+    /// Adds a node that circularizes at the apoapsis, and starts executing it.
+    fn plan_circularization(&mut self) -> KrpcResult<()> {
+        let connection = self.connection;
+        let body = self.orbit.body()?;
+        let frame = body.non_rotating_reference_frame()?;
+        let orbit = KeplerOrbit::fetch(connection, &self.orbit, &frame)?;
+        let planned = circularize_at_apoapsis(&orbit, self.ut.value()?)
+            .ok_or_else(|| Error::from(GuidanceError::NoApoapsis))?;
+
+        let call = self.control.call().add_node(
+            planned.ut,
+            planned.prograde as f32,
+            planned.normal as f32,
+            planned.radial as f32,
+        )?;
+        let results = &mut connection.invoke_batch(vec![call])?.into_iter();
+        let node: Node<'a> = decode_space_center_result(results, connection, "Control_AddNode")?;
+        self.executor = Some(NodeExecutor::new(connection, &self.vessel, &node)?);
+        self.node = Some(node);
+        Ok(())
+    }
+}
+
+/// Flies `vessel` to a circular orbit at `target_altitude`, in meters above sea level,
+/// blocking until it is in orbit. See `Ascent` for the details and settings.
+pub fn launch_to_orbit(
+    connection: &Connection,
+    vessel: &Vessel,
+    target_altitude: f64,
+) -> KrpcResult<()> {
+    Ascent::new(connection, vessel, target_altitude)?.run()
+}
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, Fail)]
+pub enum GuidanceError {
+    #[fail(display = "The vessel has no available thrust.")]
+    NoThrust,
+    #[fail(display = "The engines flamed out before the burn was complete.")]
+    Flameout,
+    #[fail(display = "The orbit has no apoapsis to circularize at.")]
+    NoApoapsis,
+    #[fail(display = "The target orbit is inside the atmosphere.")]
+    TargetInAtmosphere,
+    #[fail(display = "The vessel does not have enough thrust to land.")]
+    InsufficientThrust,
+    #[fail(display = "There is no target vessel or docking port.")]
+    NoTarget,
+    #[fail(display = "The orbits have nearly the same period, so there is no transfer window.")]
+    NoTransferWindow,
+    #[fail(display = "The vessel has no docking port that is ready to dock.")]
+    NoDockingPort,
+}
@@ -0,0 +1,399 @@
+use super::GuidanceError;
+use crate::client::{decode_space_center_result, Connection, KrpcResult, Stream};
+use crate::spacecenter::{
+    AutoPilot, CelestialBody, Control, Flight, ReferenceFrame, SpaceCenter, Vessel,
+    VesselSituation, Waypoint,
+};
+use crate::Vector3;
+
+use failure::Error;
+
+/// How quickly the descent speed is brought back to the braking profile, in 1/s.
+const SPEED_GAIN: f64 = 1.0;
+
+/// The time over which horizontal speed errors are corrected, in seconds.
+const HORIZONTAL_RESPONSE_TIME: f64 = 2.0;
+
+/// How far below the bottom of the vessel the ray that measures the height above the ground
+/// starts, so that it does not hit the vessel itself, in meters.
+const RAY_CLEARANCE: f64 = 1.0;
+
+/// Returns the time, in seconds, until a vessel falling freely must start a full-thrust burn
+/// to stop at the ground, or `None` if the thrust cannot overcome gravity.
+///
+/// `altitude` is the height above the ground, in meters, `vertical_speed` is in m/s and
+/// positive upwards, `acceleration` is the acceleration from the engines at full thrust, in
+/// m/s², and `gravity` is the surface gravity, in m/s². The burn is assumed to be vertical. A
+/// negative time means that the burn should already have started.
+pub fn suicide_burn_start(
+    altitude: f64,
+    vertical_speed: f64,
+    acceleration: f64,
+    gravity: f64,
+) -> Option<f64> {
+    if acceleration <= gravity {
+        return None;
+    }
+    // Falling for t seconds from a descent speed s reaches speed u = s + g t after dropping
+    // (u² - s²) / 2g, and braking from u takes u² / 2(a - g) more.
+    let descent_speed = -vertical_speed;
+    let deceleration = acceleration - gravity;
+    let burn_speed = ((2.0 * altitude * gravity + descent_speed * descent_speed) * deceleration
+        / acceleration)
+        .max(0.0)
+        .sqrt();
+    Some((burn_speed - descent_speed) / gravity)
+}
+
+/// The phases of a landing, in order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LandingPhase {
+    /// Falling with the engines off, pointing retrograde, until the suicide burn starts. The
+    /// engines may be shut down or not yet staged while falling, as long as there is thrust
+    /// again by the time the burn starts.
+    Falling,
+    /// Braking to land, steering towards the target if there is one.
+    Braking,
+    /// On the ground, with the engines off and the auto-pilot disengaged.
+    Landed,
+}
+
+/// Lands a vessel that is falling towards the surface of a body, with a suicide burn.
+///
+/// The vessel falls with its engines off until it has to start braking, then follows a
+/// braking profile in which the descent speed falls to the touchdown speed at the ground,
+/// using only part of the available thrust so that there is some left to correct errors. Any
+/// thrust not needed to brake is used to cancel the horizontal speed or, when there is a
+/// target, to steer towards it. The legs are deployed when braking starts.
+///
+/// The height above the ground is measured with a ray cast down from just below the bottom of
+/// the vessel, falling back to `Flight::surface_altitude` when the ray misses, such as in the
+/// last meter. The vessel should already be on a trajectory that meets the ground near the
+/// target.
+///
+/// Like `NodeExecutor`, it can be run to completion with `run` or stepped, and both block.
+pub struct Landing<'a> {
+    space_center: SpaceCenter<'a>,
+    auto_pilot: AutoPilot<'a>,
+    control: Control<'a>,
+    body: CelestialBody<'a>,
+    reference_frame: ReferenceFrame<'a>,
+    phase: LandingPhase,
+    gravity: f64,
+    /// The acceleration at full thrust that the burn is planned with, in m/s²: with the
+    /// available thrust the last time there was any, or with the largest engine before then.
+    planned_acceleration: f64,
+    target: Option<Vector3>,
+    ut: Stream<'a, f64>,
+    position: Stream<'a, Vector3>,
+    velocity: Stream<'a, Vector3>,
+    surface_altitude: Stream<'a, f64>,
+    vertical_speed: Stream<'a, f64>,
+    available_thrust: Stream<'a, f32>,
+    mass: Stream<'a, f32>,
+    situation: Stream<'a, VesselSituation>,
+    bounding_box: Stream<'a, (Vector3, Vector3)>,
+    margin: f64,
+    touchdown_speed: f64,
+    max_horizontal_speed: f64,
+}
+
+impl<'a> Landing<'a> {
+    /// Creates a landing of `vessel` on the body it is orbiting, cuts the throttle and engages
+    /// the auto-pilot. Returns `GuidanceError::NoThrust` if the vessel has no engines to plan
+    /// the suicide burn with.
+    ///
+    /// If the engines are shut down or not yet staged, the burn is planned with the thrust of
+    /// the largest engine until there is available thrust. For a cluster of engines that is
+    /// too little, which starts the burn early rather than late.
+    pub fn new(connection: &'a Connection, vessel: &Vessel<'a>) -> KrpcResult<Self> {
+        let calls = vessel.call();
+        let batch = vec![
+            calls.auto_pilot()?,
+            calls.control()?,
+            calls.reference_frame()?,
+        ];
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let auto_pilot: AutoPilot<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_AutoPilot")?;
+        let control: Control<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_Control")?;
+        let vessel_frame: ReferenceFrame<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_ReferenceFrame")?;
+
+        let results = &mut connection
+            .invoke_batch(vec![vessel.orbit()?.call().body()?])?
+            .into_iter();
+        let body: CelestialBody<'a> =
+            decode_space_center_result(results, connection, "Orbit_get_Body")?;
+        let results = &mut connection
+            .invoke_batch(vec![body.call().reference_frame()?])?
+            .into_iter();
+        let reference_frame: ReferenceFrame<'a> =
+            decode_space_center_result(results, connection, "CelestialBody_get_ReferenceFrame")?;
+        let results = &mut connection
+            .invoke_batch(vec![calls.flight(Some(&reference_frame))?])?
+            .into_iter();
+        let flight: Flight<'a> = decode_space_center_result(results, connection, "Vessel_Flight")?;
+
+        let gravity = f64::from(body.surface_gravity()?);
+        let mut thrust = f64::from(vessel.available_thrust()?);
+        if thrust <= 0.0 {
+            let parts = vessel.parts()?;
+            let engines = parts.engines()?;
+            let batch = engines
+                .iter()
+                .map(|engine| engine.call().max_thrust())
+                .collect::<KrpcResult<Vec<_>>>()?;
+            let results = &mut connection.invoke_batch(batch)?.into_iter();
+            for _ in engines.iter() {
+                let max_thrust: f32 =
+                    decode_space_center_result(results, connection, "Engine_get_MaxThrust")?;
+                thrust = thrust.max(f64::from(max_thrust));
+            }
+        }
+        if thrust <= 0.0 {
+            return Err(Error::from(GuidanceError::NoThrust));
+        }
+        let planned_acceleration = thrust / f64::from(vessel.mass()?);
+
+        control.set_throttle(0.0)?;
+        auto_pilot.set_reference_frame(&reference_frame)?;
+        auto_pilot.engage()?;
+
+        let space_center = SpaceCenter::new(connection);
+        let flight_calls = flight.call();
+        Ok(Landing {
+            ut: connection.add_stream_for_call(space_center.call().ut()?)?,
+            position: connection.add_stream_for_call(calls.position(&reference_frame)?)?,
+            velocity: connection.add_stream_for_call(flight_calls.velocity()?)?,
+            surface_altitude: connection.add_stream_for_call(flight_calls.surface_altitude()?)?,
+            vertical_speed: connection.add_stream_for_call(flight_calls.vertical_speed()?)?,
+            available_thrust: connection.add_stream_for_call(calls.available_thrust()?)?,
+            mass: connection.add_stream_for_call(calls.mass()?)?,
+            situation: connection.add_stream_for_call(calls.situation()?)?,
+            bounding_box: connection.add_stream_for_call(calls.bounding_box(&vessel_frame)?)?,
+            space_center,
+            auto_pilot,
+            control,
+            body,
+            reference_frame,
+            phase: LandingPhase::Falling,
+            gravity,
+            planned_acceleration,
+            target: None,
+            margin: 0.8,
+            touchdown_speed: 2.0,
+            max_horizontal_speed: 20.0,
+        })
+    }
+
+    /// Sets the target to land at, by latitude and longitude in degrees.
+    pub fn target(mut self, latitude: f64, longitude: f64) -> KrpcResult<Self> {
+        self.target = Some(self.body.surface_position(
+            latitude,
+            longitude,
+            &self.reference_frame,
+        )?);
+        Ok(self)
+    }
+
+    /// Sets the target to land at to a waypoint on the body being landed on.
+    pub fn target_waypoint(self, waypoint: &Waypoint) -> KrpcResult<Self> {
+        let (latitude, longitude) = (waypoint.latitude()?, waypoint.longitude()?);
+        self.target(latitude, longitude)
+    }
+
+    /// Sets the fraction of the available thrust, above what is needed to hover, that the
+    /// braking profile is planned with. Defaults to 0.8.
+    pub fn margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the descent speed at touchdown, in m/s. Defaults to 2.
+    pub fn touchdown_speed(mut self, speed: f64) -> Self {
+        self.touchdown_speed = speed;
+        self
+    }
+
+    /// Sets the fastest horizontal speed used to steer towards the target, in m/s. Defaults
+    /// to 20.
+    pub fn max_horizontal_speed(mut self, speed: f64) -> Self {
+        self.max_horizontal_speed = speed;
+        self
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> LandingPhase {
+        self.phase
+    }
+
+    /// Returns the universal time at which the suicide burn starts, as currently predicted
+    /// from the last thrust that was available, or `None` if the vessel cannot brake.
+    pub fn burn_start(&self) -> KrpcResult<Option<f64>> {
+        let acceleration = self.gravity + self.margin * (self.planned_acceleration - self.gravity);
+        let ut = self.ut.value()?;
+        Ok(suicide_burn_start(
+            self.altitude()?,
+            self.vertical_speed.value()?,
+            acceleration,
+            self.gravity,
+        )
+        .map(|time| ut + time))
+    }
+
+    /// Advances the landing by one step, and returns the phase it is then in.
+    pub fn step(&mut self) -> KrpcResult<LandingPhase> {
+        match self.phase {
+            LandingPhase::Falling => {
+                if self.has_landed()? {
+                    return self.finish();
+                }
+                let velocity = self.velocity.value()?;
+                if velocity.magnitude() > 1.0 {
+                    self.auto_pilot.set_target_direction(-velocity)?;
+                }
+                let max_acceleration = self.max_acceleration()?;
+                if max_acceleration > 0.0 {
+                    self.planned_acceleration = max_acceleration;
+                }
+                let deceleration = self.planned_deceleration()?;
+                let descent_speed = -self.vertical_speed.value()?;
+                if descent_speed >= self.braking_speed(self.altitude()?, deceleration) {
+                    if max_acceleration <= 0.0 {
+                        self.auto_pilot.disengage()?;
+                        return Err(Error::from(GuidanceError::NoThrust));
+                    }
+                    self.control.set_legs_deployed(true)?;
+                    self.phase = LandingPhase::Braking;
+                }
+            }
+            LandingPhase::Braking => {
+                if self.has_landed()? {
+                    return self.finish();
+                }
+                let max_acceleration = self.max_acceleration()?;
+                if max_acceleration <= 0.0 {
+                    self.control.set_throttle(0.0)?;
+                    self.auto_pilot.disengage()?;
+                    return Err(Error::from(GuidanceError::Flameout));
+                }
+                self.planned_acceleration = max_acceleration;
+                let acceleration = self.acceleration(self.altitude()?, max_acceleration)?;
+                if acceleration.magnitude() > 0.0 {
+                    self.auto_pilot.set_target_direction(acceleration)?;
+                }
+                let throttle = (acceleration.magnitude() / max_acceleration).min(1.0);
+                self.control.set_throttle(throttle as f32)?;
+            }
+            LandingPhase::Landed => {}
+        }
+        Ok(self.phase)
+    }
+
+    /// Runs the landing to completion, blocking until the vessel is on the ground.
+    pub fn run(&mut self) -> KrpcResult<()> {
+        while self.step()? != LandingPhase::Landed {
+            self.ut.wait()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the height of the bottom of the vessel above the ground, in meters. Casting the
+    /// ray is a call to the server, so `step` measures it once and passes it on.
+    fn altitude(&self) -> KrpcResult<f64> {
+        // The vessel's y axis points out of its nose, so its bottom, which drops as the legs
+        // deploy, is at the lowest y.
+        let height = -self.bounding_box.value()?.0.y;
+        let position = self.position.value()?;
+        let down = -position.normalize();
+        let distance = self.space_center.raycast_distance(
+            position + down * (height + RAY_CLEARANCE),
+            down,
+            &self.reference_frame,
+        )?;
+        if distance.is_finite() {
+            Ok(distance + RAY_CLEARANCE)
+        } else {
+            Ok((self.surface_altitude.value()? - height).max(0.0))
+        }
+    }
+
+    fn max_acceleration(&self) -> KrpcResult<f64> {
+        Ok(f64::from(self.available_thrust.value()?) / f64::from(self.mass.value()?))
+    }
+
+    /// Returns the deceleration that the braking profile is planned with, in m/s², from the
+    /// last thrust that was available.
+    fn planned_deceleration(&self) -> KrpcResult<f64> {
+        let deceleration = self.margin * (self.planned_acceleration - self.gravity);
+        if deceleration <= 0.0 {
+            return Err(Error::from(GuidanceError::InsufficientThrust));
+        }
+        Ok(deceleration)
+    }
+
+    /// Returns the descent speed of the braking profile at `altitude`, in m/s.
+    fn braking_speed(&self, altitude: f64, deceleration: f64) -> f64 {
+        (self.touchdown_speed * self.touchdown_speed + 2.0 * deceleration * altitude).sqrt()
+    }
+
+    /// Returns the acceleration to thrust along at `altitude`, in m/s².
+    ///
+    /// The vertical acceleration keeps the descent speed on the braking profile, and takes
+    /// priority. What is left is used to bring the horizontal velocity to the one that would
+    /// reach the target at touchdown, or to zero if there is no target.
+    fn acceleration(&self, altitude: f64, max_acceleration: f64) -> KrpcResult<Vector3> {
+        let position = self.position.value()?;
+        let velocity = self.velocity.value()?;
+        let up = position.normalize();
+
+        let deceleration = self.planned_deceleration()?;
+        let descent_speed = -self.vertical_speed.value()?;
+        let profile_speed = self.braking_speed(altitude, deceleration);
+        let vertical = (self.gravity + deceleration + SPEED_GAIN * (descent_speed - profile_speed))
+            .max(0.0)
+            .min(max_acceleration);
+
+        let wanted = match self.target {
+            Some(target) => {
+                let offset = target - position;
+                let offset = offset - offset.project_onto(up);
+                let time_to_go = (altitude / descent_speed.max(self.touchdown_speed)).max(1.0);
+                let speed = offset.magnitude() / time_to_go;
+                offset.normalize() * speed.min(self.max_horizontal_speed)
+            }
+            None => Vector3::ZERO,
+        };
+        let horizontal_velocity = velocity - velocity.project_onto(up);
+        let mut horizontal = (wanted - horizontal_velocity) / HORIZONTAL_RESPONSE_TIME;
+        let available = (max_acceleration * max_acceleration - vertical * vertical)
+            .max(0.0)
+            .sqrt();
+        if horizontal.magnitude() > available {
+            horizontal = horizontal.normalize() * available;
+        }
+        Ok(up * vertical + horizontal)
+    }
+
+    fn has_landed(&self) -> KrpcResult<bool> {
+        Ok(matches!(
+            self.situation.value()?,
+            VesselSituation::Landed | VesselSituation::Splashed
+        ))
+    }
+
+    fn finish(&mut self) -> KrpcResult<LandingPhase> {
+        self.control.set_throttle(0.0)?;
+        self.auto_pilot.disengage()?;
+        self.phase = LandingPhase::Landed;
+        Ok(self.phase)
+    }
+}
+
+/// Lands `vessel` on the body it is falling towards, blocking until it is on the ground. See
+/// `Landing` for the details and settings.
+pub fn land(connection: &Connection, vessel: &Vessel) -> KrpcResult<()> {
+    Landing::new(connection, vessel)?.run()
+}
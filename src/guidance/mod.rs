@@ -0,0 +1,18 @@
+//! Flight scripts that fly a vessel from the client, built on the `SpaceCenter` service.
+//!
+//! Each script is a state machine that is advanced one step at a time, so it can be run to
+//! completion with a blocking call, or stepped from a control loop alongside other work. Every
+//! step makes blocking calls to the server. The node executor can also be run as a future,
+//! which waits for the next game tick between steps without blocking.
+
+mod ascent;
+mod error;
+mod landing;
+mod node;
+mod rendezvous;
+
+pub use self::ascent::*;
+pub use self::error::*;
+pub use self::landing::*;
+pub use self::node::*;
+pub use self::rendezvous::*;
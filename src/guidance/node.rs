@@ -0,0 +1,264 @@
+use super::GuidanceError;
+use crate::client::{decode_space_center_result, Connection, KrpcResult, Stream};
+use crate::spacecenter::{AutoPilot, Control, Node, ReferenceFrame, SpaceCenter, Vessel};
+use crate::Vector3;
+
+use failure::Error;
+
+/// Standard gravity, which converts a specific impulse to an exhaust velocity, in m/s².
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// The lowest throttle used while burning, so that the last of the delta-v does not take
+/// forever.
+const MIN_THROTTLE: f64 = 0.05;
+
+/// Returns the time, in seconds, needed to change the velocity by `delta_v`, in m/s, with
+/// engines producing `thrust`, in Newtons, with the given specific impulse, in seconds,
+/// starting from `mass`, in kg.
+pub fn burn_time(delta_v: f64, thrust: f64, specific_impulse: f64, mass: f64) -> f64 {
+    let exhaust_velocity = specific_impulse * STANDARD_GRAVITY;
+    let final_mass = mass * (-delta_v / exhaust_velocity).exp();
+    (mass - final_mass) * exhaust_velocity / thrust
+}
+
+/// The phases of executing a maneuver node, in order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BurnPhase {
+    /// Turning to point along the burn vector.
+    Orienting,
+    /// Warping to shortly before the start of the burn.
+    Warping,
+    /// Holding the burn attitude until the burn starts.
+    Waiting,
+    /// Burning, throttling down as the remaining delta-v runs out.
+    Burning,
+    /// The burn is complete and the node has been removed.
+    Done,
+}
+
+/// Executes a maneuver node: points along the burn vector, warps to the start of the burn,
+/// burns until the remaining delta-v is used up and then removes the node.
+///
+/// The burn is centered on the time of the node, using the available thrust, specific impulse
+/// and mass of the vessel when the executor is created. If the engines flame out during the
+/// burn, such as when a stage runs dry, the executor cuts the throttle, disengages the
+/// auto-pilot and returns `GuidanceError::Flameout`, leaving the node in place so that the
+/// burn can be resumed after staging.
+///
+/// `run` executes the node to completion, and `step` can be called directly from a control
+/// loop. Both block: every step makes calls to the server, and the step that warps does not
+/// return until the warp is over. `run_async` also executes the node to completion, but waits
+/// for the next game tick between steps without blocking, so other tasks can run while it
+/// waits. Its steps still block.
+pub struct NodeExecutor<'a> {
+    space_center: SpaceCenter<'a>,
+    node: Node<'a>,
+    auto_pilot: AutoPilot<'a>,
+    control: Control<'a>,
+    phase: BurnPhase,
+    start: f64,
+    ut: Stream<'a, f64>,
+    remaining: Stream<'a, Vector3>,
+    available_thrust: Stream<'a, f32>,
+    mass: Stream<'a, f32>,
+    pointing_error: Stream<'a, f32>,
+    lead_time: f64,
+    tolerance: f64,
+    pointing_tolerance: f32,
+    warp: bool,
+}
+
+impl<'a> NodeExecutor<'a> {
+    /// Creates an executor for `node`, a maneuver node of `vessel`, and engages the auto-pilot
+    /// to point along its burn vector.
+    pub fn new(
+        connection: &'a Connection,
+        vessel: &Vessel<'a>,
+        node: &Node<'a>,
+    ) -> KrpcResult<Self> {
+        let vessel_calls = vessel.call();
+        let node_calls = node.call();
+        let batch = vec![
+            vessel_calls.available_thrust()?,
+            vessel_calls.isp()?,
+            vessel_calls.mass()?,
+            vessel_calls.auto_pilot()?,
+            vessel_calls.control()?,
+            node_calls.remaining_deltav()?,
+            node_calls.ut()?,
+            node_calls.reference_frame()?,
+        ];
+
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let thrust: f32 =
+            decode_space_center_result(results, connection, "Vessel_get_AvailableThrust")?;
+        let isp: f32 =
+            decode_space_center_result(results, connection, "Vessel_get_SpecificImpulse")?;
+        let mass: f32 = decode_space_center_result(results, connection, "Vessel_get_Mass")?;
+        let auto_pilot: AutoPilot<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_AutoPilot")?;
+        let control: Control<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_Control")?;
+        let delta_v: f64 =
+            decode_space_center_result(results, connection, "Node_get_RemainingDeltaV")?;
+        let ut: f64 = decode_space_center_result(results, connection, "Node_get_UT")?;
+        let frame: ReferenceFrame =
+            decode_space_center_result(results, connection, "Node_get_ReferenceFrame")?;
+
+        if thrust <= 0.0 {
+            return Err(Error::from(GuidanceError::NoThrust));
+        }
+        let half = burn_time(
+            delta_v / 2.0,
+            f64::from(thrust),
+            f64::from(isp),
+            f64::from(mass),
+        );
+
+        auto_pilot.set_reference_frame(&frame)?;
+        auto_pilot.set_target_direction(node.burn_vector(Some(&frame))?)?;
+        auto_pilot.engage()?;
+
+        let space_center = SpaceCenter::new(connection);
+        Ok(NodeExecutor {
+            ut: connection.add_stream_for_call(space_center.call().ut()?)?,
+            remaining: connection
+                .add_stream_for_call(node.call().remaining_burn_vector(Some(&frame))?)?,
+            available_thrust: connection.add_stream_for_call(vessel_calls.available_thrust()?)?,
+            mass: connection.add_stream_for_call(vessel_calls.mass()?)?,
+            pointing_error: connection.add_stream_for_call(auto_pilot.call().error()?)?,
+            space_center,
+            node: node.clone(),
+            control,
+            auto_pilot,
+            phase: BurnPhase::Orienting,
+            start: ut - half,
+            lead_time: 10.0,
+            tolerance: 0.1,
+            pointing_tolerance: 1.0,
+            warp: true,
+        })
+    }
+
+    /// Sets how long before the start of the burn the warp stops, in seconds. Defaults to 10.
+    pub fn lead_time(mut self, seconds: f64) -> Self {
+        self.lead_time = seconds;
+        self
+    }
+
+    /// Sets the remaining delta-v at which the burn is complete, in m/s. Defaults to 0.1.
+    pub fn tolerance(mut self, delta_v: f64) -> Self {
+        self.tolerance = delta_v;
+        self
+    }
+
+    /// Sets how far from the burn vector the vessel may point before warping, in degrees.
+    /// Defaults to 1.
+    pub fn pointing_tolerance(mut self, degrees: f32) -> Self {
+        self.pointing_tolerance = degrees;
+        self
+    }
+
+    /// Sets whether to warp to the start of the burn. Defaults to `true`.
+    pub fn warp(mut self, warp: bool) -> Self {
+        self.warp = warp;
+        self
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> BurnPhase {
+        self.phase
+    }
+
+    /// Returns the universal time at which the burn starts.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// Advances the burn by one step, and returns the phase it is then in.
+    pub fn step(&mut self) -> KrpcResult<BurnPhase> {
+        match self.phase {
+            BurnPhase::Orienting => {
+                if self.pointing_error.value()? <= self.pointing_tolerance {
+                    self.phase = if self.warp {
+                        BurnPhase::Warping
+                    } else {
+                        BurnPhase::Waiting
+                    };
+                }
+            }
+            BurnPhase::Warping => {
+                let until = self.start - self.lead_time;
+                if self.ut.value()? < until {
+                    self.space_center.warp_to_with(until).call()?;
+                }
+                self.phase = BurnPhase::Waiting;
+            }
+            BurnPhase::Waiting => {
+                if self.ut.value()? >= self.start {
+                    self.phase = BurnPhase::Burning;
+                }
+            }
+            BurnPhase::Burning => {
+                // The node's reference frame has its y axis along the original burn vector, so
+                // the burn is over once the y component is used up or overshot.
+                let remaining = self.remaining.value()?.y;
+                if remaining <= self.tolerance {
+                    self.control.set_throttle(0.0)?;
+                    self.auto_pilot.disengage()?;
+                    self.node.remove()?;
+                    self.phase = BurnPhase::Done;
+                } else {
+                    let thrust = f64::from(self.available_thrust.value()?);
+                    if thrust <= 0.0 {
+                        self.control.set_throttle(0.0)?;
+                        self.auto_pilot.disengage()?;
+                        return Err(Error::from(GuidanceError::Flameout));
+                    }
+
+                    // Throttle down over the last second at full thrust.
+                    let full_thrust_time = remaining * f64::from(self.mass.value()?) / thrust;
+                    let throttle = full_thrust_time.clamp(MIN_THROTTLE, 1.0);
+                    self.control.set_throttle(throttle as f32)?;
+                }
+            }
+            BurnPhase::Done => {}
+        }
+        Ok(self.phase)
+    }
+
+    /// Runs the burn to completion, blocking until the node has been executed.
+    pub fn run(&mut self) -> KrpcResult<()> {
+        while self.step()? != BurnPhase::Done {
+            self.ut.wait()?;
+        }
+        Ok(())
+    }
+
+    /// Runs the burn to completion, waiting for an update of the universal time between steps
+    /// without blocking.
+    pub async fn run_async(&mut self) -> KrpcResult<()> {
+        while self.step()? != BurnPhase::Done {
+            self.ut.wait_async().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Executes `node`, a maneuver node of `vessel`, blocking until it is done. See
+/// `NodeExecutor` for the details and settings.
+pub fn execute_node(connection: &Connection, vessel: &Vessel, node: &Node) -> KrpcResult<()> {
+    NodeExecutor::new(connection, vessel, node)?.run()
+}
+
+/// Executes `node`, a maneuver node of `vessel`, asynchronously. See
+/// `NodeExecutor::run_async` for what blocks and what does not.
+pub async fn execute_node_async<'a>(
+    connection: &'a Connection,
+    vessel: &Vessel<'a>,
+    node: &Node<'a>,
+) -> KrpcResult<()> {
+    NodeExecutor::new(connection, vessel, node)?
+        .run_async()
+        .await
+}
@@ -0,0 +1,623 @@
+use super::{burn_time, BurnPhase, GuidanceError, NodeExecutor};
+use crate::client::{decode_space_center_result, Connection, KrpcResult, Stream};
+use crate::kepler::maneuver::hohmann_transfer;
+use crate::kepler::KeplerOrbit;
+use crate::spacecenter::{
+    AutoPilot, Control, DockingPort, DockingPortState, Node, Orbit, Part, ReferenceFrame,
+    SpaceCenter, Vessel,
+};
+use crate::{Quaternion, Vector3};
+
+use failure::Error;
+
+/// The lowest throttle used while matching velocities.
+const MIN_THROTTLE: f64 = 0.05;
+
+/// How far from the wanted direction the vessel may point while burning, in degrees.
+const BURN_POINTING_TOLERANCE: f32 = 5.0;
+
+/// How long closing in on the target should take, in seconds, which sets the closing speed.
+const CLOSING_TIME: f64 = 60.0;
+
+/// The slowest closing speed, in m/s.
+const MIN_CLOSING_SPEED: f64 = 1.0;
+
+/// The fraction of the closing speed that the relative velocity may drift by before it is
+/// corrected.
+const CLOSING_DRIFT: f64 = 0.25;
+
+/// How strongly the RCS thrusters respond to velocity errors during the approach, in s/m.
+const TRANSLATION_GAIN: f64 = 2.0;
+
+/// How long the approach takes to fix position errors, in seconds.
+const APPROACH_TIME: f64 = 10.0;
+
+/// How far the docking port may be from the axis of the target port before moving in along
+/// it, in meters.
+const ALIGNMENT_TOLERANCE: f64 = 0.5;
+
+/// Returns the velocity to approach the target port with, in m/s, from the position of the
+/// docking port, both in the reference frame of the target port, whose y axis points out of
+/// its front.
+///
+/// The velocity always moves the port towards the target port's axis. Until the port is
+/// within half a meter of the axis, it also moves to, and holds at, `standoff_distance` along
+/// the axis. Once lined up, it moves in along the axis at
+/// `approach_speed`, slowing to half of it close to the target port.
+pub fn approach_velocity(
+    position: Vector3,
+    standoff_distance: f64,
+    approach_speed: f64,
+) -> Vector3 {
+    let offset = Vector3::new(position.x, 0.0, position.z);
+    let along = if offset.magnitude() > ALIGNMENT_TOLERANCE {
+        (standoff_distance - position.y) / APPROACH_TIME
+    } else {
+        -(position.y / APPROACH_TIME)
+            .max(approach_speed / 2.0)
+            .min(approach_speed)
+    };
+    -offset / APPROACH_TIME + Vector3::new(0.0, along, 0.0)
+}
+
+/// Returns the RCS translation controls, as `(forward, right, up)`, for a translation in the
+/// reference frame of the vessel, whose y axis points forwards, x axis to the right and z axis
+/// out of the bottom. Each control is clamped to between -1 and 1.
+pub fn translation_controls(translation: Vector3) -> (f32, f32, f32) {
+    let clamp = |value: f64| value.clamp(-1.0, 1.0) as f32;
+    (
+        clamp(translation.y),
+        clamp(translation.x),
+        clamp(-translation.z),
+    )
+}
+
+/// The phases of a rendezvous, in order.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RendezvousPhase {
+    /// Executing a transfer burn to intercept the target, unless the orbits already pass
+    /// close to it.
+    Transfer,
+    /// Coasting, or warping, to the closest approach.
+    Coasting,
+    /// Burning to kill the velocity relative to the target.
+    Braking,
+    /// Burning towards the target, then coasting until close to it.
+    Closing,
+    /// Translating with RCS to line the docking ports up and move in.
+    Approaching,
+    /// The docking ports are pulling together.
+    Docking,
+    /// Stopped near the target or, when docking, docked.
+    Done,
+}
+
+/// A snapshot of the progress of a rendezvous.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RendezvousStatus {
+    pub phase: RendezvousPhase,
+    /// The distance to the target, in meters.
+    pub distance: f64,
+    /// The speed relative to the target, in m/s.
+    pub relative_speed: f64,
+    /// The distance to the target at the closest approach of the current orbits, in meters.
+    pub closest_approach: f64,
+}
+
+/// Flies a vessel to another vessel orbiting the same body and, optionally, docks with it.
+///
+/// If the current orbits do not already pass close to the target, a Hohmann transfer is
+/// planned and executed with a `NodeExecutor`, which suits near-circular orbits in the same
+/// plane; `kepler::maneuver::match_planes` can be used first otherwise. A target in nearly the
+/// same orbit has no transfer window, and gives `GuidanceError::NoTransferWindow`, so the
+/// vessel must be put on a phasing orbit first. The vessel then coasts, warping if allowed,
+/// to the closest approach, kills its velocity relative to the target with its main engines
+/// and closes in until it is within the approach distance.
+///
+/// With docking ports set, it then points its port at the target's using the auto-pilot and
+/// translates with RCS to line it up with the target port's axis, holding at the standoff
+/// distance, then moves in along the axis until the ports dock. See `approach_velocity`. The
+/// vessel should be in front of the target port for the final approach, as obstacles are not
+/// avoided.
+///
+/// Every transition is driven by streams, of the closest approach, the relative position and
+/// velocity and the state of the docking port, and `status` reports the progress. Like
+/// `NodeExecutor`, it can be run to completion with `run` or stepped, and both block.
+pub struct Rendezvous<'a> {
+    connection: &'a Connection,
+    space_center: SpaceCenter<'a>,
+    vessel: Vessel<'a>,
+    orbit: Orbit<'a>,
+    target_orbit: Orbit<'a>,
+    auto_pilot: AutoPilot<'a>,
+    control: Control<'a>,
+    orbit_frame: ReferenceFrame<'a>,
+    relative_frame: ReferenceFrame<'a>,
+    phase: RendezvousPhase,
+    transfer: Option<NodeExecutor<'a>>,
+    brake_at: f64,
+    burning: bool,
+    ports: Option<(DockingPort<'a>, DockingPort<'a>)>,
+    approach: Option<Approach<'a>>,
+    ut: Stream<'a, f64>,
+    position: Stream<'a, Vector3>,
+    velocity: Stream<'a, Vector3>,
+    closest_approach: Stream<'a, f64>,
+    closest_approach_time: Stream<'a, f64>,
+    available_thrust: Stream<'a, f32>,
+    mass: Stream<'a, f32>,
+    pointing_error: Stream<'a, f32>,
+    intercept_distance: f64,
+    approach_distance: f64,
+    max_closing_speed: f64,
+    speed_tolerance: f64,
+    standoff_distance: f64,
+    approach_speed: f64,
+    lead_time: f64,
+    warp: bool,
+}
+
+/// The streams used during the final approach, in the reference frame of the target port.
+struct Approach<'a> {
+    position: Stream<'a, Vector3>,
+    velocity: Stream<'a, Vector3>,
+    /// The rotation of the target port, in the reference frame of the vessel.
+    rotation: Stream<'a, Quaternion>,
+    state: Stream<'a, DockingPortState>,
+}
+
+impl<'a> Rendezvous<'a> {
+    /// Creates a rendezvous of `vessel` with `target`.
+    pub fn new(
+        connection: &'a Connection,
+        vessel: &Vessel<'a>,
+        target: &Vessel<'a>,
+    ) -> KrpcResult<Self> {
+        let calls = vessel.call();
+        let batch = vec![
+            calls.auto_pilot()?,
+            calls.control()?,
+            calls.orbit()?,
+            target.call().orbit()?,
+            target.call().reference_frame()?,
+        ];
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let auto_pilot: AutoPilot<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_AutoPilot")?;
+        let control: Control<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_Control")?;
+        let orbit: Orbit<'a> = decode_space_center_result(results, connection, "Vessel_get_Orbit")?;
+        let target_orbit: Orbit<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_Orbit")?;
+        let target_frame: ReferenceFrame<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_ReferenceFrame")?;
+
+        let body = orbit.body()?;
+        let results = &mut connection
+            .invoke_batch(vec![body.call().non_rotating_reference_frame()?])?
+            .into_iter();
+        let orbit_frame: ReferenceFrame<'a> = decode_space_center_result(
+            results,
+            connection,
+            "CelestialBody_get_NonRotatingReferenceFrame",
+        )?;
+
+        // Centered on the target and moving with it, but not rotating with it, so that the
+        // relative velocity is not disturbed by the target turning.
+        let relative_frame = ReferenceFrame::create_hybrid(
+            connection,
+            &target_frame,
+            Some(&orbit_frame),
+            None,
+            Some(&orbit_frame),
+        )?;
+
+        let space_center = SpaceCenter::new(connection);
+        let orbit_calls = orbit.call();
+        Ok(Rendezvous {
+            ut: connection.add_stream_for_call(space_center.call().ut()?)?,
+            position: connection.add_stream_for_call(calls.position(&relative_frame)?)?,
+            velocity: connection.add_stream_for_call(calls.velocity(&relative_frame)?)?,
+            closest_approach: connection
+                .add_stream_for_call(orbit_calls.distance_at_closest_approach(&target_orbit)?)?,
+            closest_approach_time: connection
+                .add_stream_for_call(orbit_calls.time_of_closest_approach(&target_orbit)?)?,
+            available_thrust: connection.add_stream_for_call(calls.available_thrust()?)?,
+            mass: connection.add_stream_for_call(calls.mass()?)?,
+            pointing_error: connection.add_stream_for_call(auto_pilot.call().error()?)?,
+            connection,
+            space_center,
+            vessel: vessel.clone(),
+            orbit,
+            target_orbit,
+            auto_pilot,
+            control,
+            orbit_frame,
+            relative_frame,
+            phase: RendezvousPhase::Transfer,
+            transfer: None,
+            brake_at: 0.0,
+            burning: false,
+            ports: None,
+            approach: None,
+            intercept_distance: 1000.0,
+            approach_distance: 200.0,
+            max_closing_speed: 10.0,
+            speed_tolerance: 0.1,
+            standoff_distance: 20.0,
+            approach_speed: 0.5,
+            lead_time: 30.0,
+            warp: true,
+        })
+    }
+
+    /// Sets the docking ports to dock with, a port of the vessel and a port of the target.
+    /// Without them, the rendezvous ends once stopped within the approach distance.
+    pub fn docking_ports(mut self, port: &DockingPort<'a>, target_port: &DockingPort<'a>) -> Self {
+        self.ports = Some((port.clone(), target_port.clone()));
+        self
+    }
+
+    /// Sets how close the orbits must pass to the target, in meters, for no transfer to be
+    /// needed. Defaults to 1000.
+    pub fn intercept_distance(mut self, distance: f64) -> Self {
+        self.intercept_distance = distance;
+        self
+    }
+
+    /// Sets how close to the target to close in to with the main engines, in meters. Defaults
+    /// to 200.
+    pub fn approach_distance(mut self, distance: f64) -> Self {
+        self.approach_distance = distance;
+        self
+    }
+
+    /// Sets the fastest speed to close in on the target at, in m/s. Defaults to 10.
+    pub fn max_closing_speed(mut self, speed: f64) -> Self {
+        self.max_closing_speed = speed;
+        self
+    }
+
+    /// Sets the relative speed below which the velocities are matched, in m/s. Defaults to
+    /// 0.1.
+    pub fn speed_tolerance(mut self, speed: f64) -> Self {
+        self.speed_tolerance = speed;
+        self
+    }
+
+    /// Sets how far in front of the target port the docking port is lined up before moving
+    /// in, in meters. Defaults to 20.
+    pub fn standoff_distance(mut self, distance: f64) -> Self {
+        self.standoff_distance = distance;
+        self
+    }
+
+    /// Sets the speed of the final approach to the target port, in m/s. Defaults to 0.5.
+    pub fn approach_speed(mut self, speed: f64) -> Self {
+        self.approach_speed = speed;
+        self
+    }
+
+    /// Sets how long before braking the warp to the closest approach stops, in seconds.
+    /// Defaults to 30.
+    pub fn lead_time(mut self, seconds: f64) -> Self {
+        self.lead_time = seconds;
+        self
+    }
+
+    /// Sets whether to warp to the closest approach. Defaults to `true`.
+    pub fn warp(mut self, warp: bool) -> Self {
+        self.warp = warp;
+        self
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> RendezvousPhase {
+        self.phase
+    }
+
+    /// Returns the current progress.
+    pub fn status(&self) -> KrpcResult<RendezvousStatus> {
+        Ok(RendezvousStatus {
+            phase: self.phase,
+            distance: self.position.value()?.magnitude(),
+            relative_speed: self.velocity.value()?.magnitude(),
+            closest_approach: self.closest_approach.value()?,
+        })
+    }
+
+    /// Advances the rendezvous by one step, and returns the phase it is then in.
+    pub fn step(&mut self) -> KrpcResult<RendezvousPhase> {
+        match self.phase {
+            RendezvousPhase::Transfer => {
+                if self.transfer.is_none() {
+                    // Skip the transfer if the target is close, or will be.
+                    if self.position.value()?.magnitude() <= self.intercept_distance {
+                        self.phase = RendezvousPhase::Braking;
+                        self.engage()?;
+                        return Ok(self.phase);
+                    }
+                    if self.closest_approach.value()? <= self.intercept_distance {
+                        self.start_coasting()?;
+                        return Ok(self.phase);
+                    }
+                    self.plan_transfer()?;
+                }
+                if let Some(executor) = self.transfer.as_mut() {
+                    if executor.step()? == BurnPhase::Done {
+                        self.transfer = None;
+                        self.start_coasting()?;
+                    }
+                }
+            }
+            RendezvousPhase::Coasting => {
+                let velocity = self.velocity.value()?;
+                self.auto_pilot.set_target_direction(-velocity)?;
+                let ut = self.ut.value()?;
+                if ut >= self.brake_at {
+                    self.phase = RendezvousPhase::Braking;
+                } else if self.warp && ut < self.brake_at - self.lead_time {
+                    self.space_center
+                        .warp_to_with(self.brake_at - self.lead_time)
+                        .call()?;
+                }
+            }
+            RendezvousPhase::Braking => {
+                if self.match_velocity(Vector3::ZERO)? {
+                    let distance = self.position.value()?.magnitude();
+                    if distance > self.approach_distance {
+                        self.burning = true;
+                        self.phase = RendezvousPhase::Closing;
+                    } else if self.ports.is_some() {
+                        self.start_approach()?;
+                    } else {
+                        self.finish()?;
+                    }
+                }
+            }
+            RendezvousPhase::Closing => {
+                let position = self.position.value()?;
+                let distance = position.magnitude();
+                let speed = (distance / CLOSING_TIME)
+                    .max(MIN_CLOSING_SPEED)
+                    .min(self.max_closing_speed);
+                let wanted = -position.normalize() * speed;
+                if distance <= self.approach_distance {
+                    self.phase = RendezvousPhase::Braking;
+                } else if self.burning {
+                    self.burning = !self.match_velocity(wanted)?;
+                } else if (wanted - self.velocity.value()?).magnitude() > CLOSING_DRIFT * speed {
+                    self.burning = true;
+                }
+            }
+            RendezvousPhase::Approaching => self.approach()?,
+            RendezvousPhase::Docking => {
+                if let Some(approach) = self.approach.as_ref() {
+                    if approach.state.value()? == DockingPortState::Docked {
+                        self.approach = None;
+                        self.phase = RendezvousPhase::Done;
+                    }
+                }
+            }
+            RendezvousPhase::Done => {}
+        }
+        Ok(self.phase)
+    }
+
+    /// Runs the rendezvous to completion, blocking until it is done.
+    pub fn run(&mut self) -> KrpcResult<()> {
+        while self.step()? != RendezvousPhase::Done {
+            self.ut.wait()?;
+        }
+        Ok(())
+    }
+
+    /// Adds the departure node of a Hohmann transfer to the target's orbit, and starts
+    /// executing it. Returns `GuidanceError::NoTransferWindow` if the orbits have nearly the
+    /// same period.
+    fn plan_transfer(&mut self) -> KrpcResult<()> {
+        let connection = self.connection;
+        let orbit = KeplerOrbit::fetch(connection, &self.orbit, &self.orbit_frame)?;
+        let target = KeplerOrbit::fetch(connection, &self.target_orbit, &self.orbit_frame)?;
+        let [departure, _] = hohmann_transfer(&orbit, &target, self.ut.value()?)
+            .ok_or(GuidanceError::NoTransferWindow)?;
+
+        let call = self.control.call().add_node(
+            departure.ut,
+            departure.prograde as f32,
+            departure.normal as f32,
+            departure.radial as f32,
+        )?;
+        let results = &mut connection.invoke_batch(vec![call])?.into_iter();
+        let node: Node<'a> = decode_space_center_result(results, connection, "Control_AddNode")?;
+        self.transfer = Some(NodeExecutor::new(connection, &self.vessel, &node)?.warp(self.warp));
+        Ok(())
+    }
+
+    /// Works out when to start braking, so that the braking burn is centered on the closest
+    /// approach, and starts coasting to it.
+    fn start_coasting(&mut self) -> KrpcResult<()> {
+        let connection = self.connection;
+        let time = self.closest_approach_time.value()?;
+        let orbit = KeplerOrbit::fetch(connection, &self.orbit, &self.orbit_frame)?;
+        let target = KeplerOrbit::fetch(connection, &self.target_orbit, &self.orbit_frame)?;
+        let relative_speed = (orbit.velocity_at(time) - target.velocity_at(time)).magnitude();
+
+        let thrust = f64::from(self.available_thrust.value()?);
+        if thrust <= 0.0 {
+            return Err(Error::from(GuidanceError::NoThrust));
+        }
+        let isp = f64::from(self.vessel.isp()?);
+        let mass = f64::from(self.mass.value()?);
+        self.brake_at = time - burn_time(relative_speed, thrust, isp, mass) / 2.0;
+        self.phase = RendezvousPhase::Coasting;
+        self.engage()
+    }
+
+    /// Points the auto-pilot in the reference frame that moves with the target.
+    fn engage(&self) -> KrpcResult<()> {
+        self.auto_pilot.set_reference_frame(&self.relative_frame)?;
+        self.auto_pilot.engage()
+    }
+
+    /// Burns to bring the velocity relative to the target to `wanted`, and returns `true`,
+    /// with the throttle cut, once it is within the speed tolerance.
+    fn match_velocity(&self, wanted: Vector3) -> KrpcResult<bool> {
+        let error = wanted - self.velocity.value()?;
+        if error.magnitude() <= self.speed_tolerance {
+            self.control.set_throttle(0.0)?;
+            return Ok(true);
+        }
+        self.auto_pilot.set_target_direction(error)?;
+        if self.pointing_error.value()? > BURN_POINTING_TOLERANCE {
+            self.control.set_throttle(0.0)?;
+            return Ok(false);
+        }
+
+        let thrust = f64::from(self.available_thrust.value()?);
+        if thrust <= 0.0 {
+            self.control.set_throttle(0.0)?;
+            self.auto_pilot.disengage()?;
+            return Err(Error::from(GuidanceError::Flameout));
+        }
+        let throttle =
+            (error.magnitude() * f64::from(self.mass.value()?) / thrust).clamp(MIN_THROTTLE, 1.0);
+        self.control.set_throttle(throttle as f32)?;
+        Ok(false)
+    }
+
+    /// Controls the vessel from its docking port, points it at the target port and starts
+    /// the approach.
+    fn start_approach(&mut self) -> KrpcResult<()> {
+        let (port, target_port) = match self.ports.as_ref() {
+            Some(ports) => ports,
+            None => return Ok(()),
+        };
+        let connection = self.connection;
+        self.control.set_throttle(0.0)?;
+        self.vessel.parts()?.set_controlling_part(&port.part()?)?;
+        self.control.set_rcs_enabled(true)?;
+
+        let batch = vec![
+            self.vessel.call().reference_frame()?,
+            target_port.call().reference_frame()?,
+        ];
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let vessel_frame: ReferenceFrame<'a> =
+            decode_space_center_result(results, connection, "Vessel_get_ReferenceFrame")?;
+        let target_frame: ReferenceFrame<'a> =
+            decode_space_center_result(results, connection, "DockingPort_get_ReferenceFrame")?;
+
+        // The target port faces along its y axis, so point back along it.
+        self.auto_pilot.set_reference_frame(&target_frame)?;
+        self.auto_pilot
+            .set_target_direction(Vector3::new(0.0, -1.0, 0.0))?;
+        self.auto_pilot.engage()?;
+
+        self.approach = Some(Approach {
+            position: connection.add_stream_for_call(port.call().positiion(&target_frame)?)?,
+            velocity: connection
+                .add_stream_for_call(self.vessel.call().velocity(&target_frame)?)?,
+            rotation: connection
+                .add_stream_for_call(target_port.call().rotation(&vessel_frame)?)?,
+            state: connection.add_stream_for_call(port.call().state()?)?,
+        });
+        self.phase = RendezvousPhase::Approaching;
+        Ok(())
+    }
+
+    /// Translates to line the docking port up with the target port's axis, holding at the
+    /// standoff distance, then moves in along it.
+    fn approach(&mut self) -> KrpcResult<()> {
+        let approach = match self.approach.as_ref() {
+            Some(approach) => approach,
+            None => return Ok(()),
+        };
+        match approach.state.value()? {
+            DockingPortState::Docking | DockingPortState::Docked => {
+                self.translate(Vector3::ZERO)?;
+                self.auto_pilot.disengage()?;
+                self.control.set_rcs_enabled(false)?;
+                self.phase = RendezvousPhase::Docking;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let wanted = approach_velocity(
+            approach.position.value()?,
+            self.standoff_distance,
+            self.approach_speed,
+        );
+        let error = wanted - approach.velocity.value()?;
+
+        if self.pointing_error.value()? > BURN_POINTING_TOLERANCE {
+            self.translate(Vector3::ZERO)
+        } else {
+            let rotation = approach.rotation.value()?;
+            self.translate(rotation.rotate(error) * TRANSLATION_GAIN)
+        }
+    }
+
+    /// Sets the RCS translation controls from a vector in the reference frame of the vessel.
+    /// See `translation_controls`.
+    fn translate(&self, translation: Vector3) -> KrpcResult<()> {
+        let (forward, right, up) = translation_controls(translation);
+        self.control.set_translation_forward(forward)?;
+        self.control.set_translation_right(right)?;
+        self.control.set_translation_up(up)
+    }
+
+    fn finish(&mut self) -> KrpcResult<()> {
+        self.control.set_throttle(0.0)?;
+        self.auto_pilot.disengage()?;
+        self.phase = RendezvousPhase::Done;
+        Ok(())
+    }
+}
+
+/// Flies `vessel` to the target vessel, blocking until it has stopped near it. If a target
+/// docking port is set, it then docks with it using the first ready docking port of `vessel`.
+/// See `Rendezvous` for the details and settings.
+pub fn rendezvous_with_target(connection: &Connection, vessel: &Vessel) -> KrpcResult<()> {
+    let space_center = SpaceCenter::new(connection);
+    let results = &mut connection
+        .invoke_batch(vec![
+            space_center.call().target_vessel()?,
+            space_center.call().target_docking_port()?,
+        ])?
+        .into_iter();
+    let target_vessel: Option<Vessel> =
+        decode_space_center_result(results, connection, "get_TargetVessel")?;
+    let target_port: Option<DockingPort> =
+        decode_space_center_result(results, connection, "get_TargetDockingPort")?;
+
+    let parts = vessel.parts()?;
+    let mut rendezvous = match (target_vessel, target_port) {
+        (_, Some(target_port)) => {
+            let results = &mut connection
+                .invoke_batch(vec![target_port.call().part()?])?
+                .into_iter();
+            let part: Part =
+                decode_space_center_result(results, connection, "DockingPort_get_Part")?;
+            let results = &mut connection
+                .invoke_batch(vec![part.call().vessel()?])?
+                .into_iter();
+            let target: Vessel =
+                decode_space_center_result(results, connection, "Part_get_Vessel")?;
+
+            let mut port = None;
+            for candidate in parts.docking_ports()? {
+                if candidate.state()? == DockingPortState::Ready {
+                    port = Some(candidate);
+                    break;
+                }
+            }
+            let port = port.ok_or_else(|| Error::from(GuidanceError::NoDockingPort))?;
+            Rendezvous::new(connection, vessel, &target)?.docking_ports(&port, &target_port)
+        }
+        (Some(target), None) => Rendezvous::new(connection, vessel, &target)?,
+        (None, None) => return Err(Error::from(GuidanceError::NoTarget)),
+    };
+    rendezvous.run()
+}
@@ -1,4 +1,3 @@
-use crate::codec::{Decode, Encode};
 use crate::remote_type;
 use crate::spacecenter::Vessel;
 
@@ -1,6 +1,5 @@
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::Part;
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// A group of servos.
@@ -0,0 +1,60 @@
+use crate::Vector3;
+
+/// The classical elements of an orbit, using the same conventions as `Orbit`. Angles are in
+/// radians and times are universal times, in seconds.
+///
+/// The size of the orbit is given by the periapsis rather than the semi-major axis, which is
+/// infinite for a parabolic orbit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitalElements {
+    /// The distance from the center of the body to the periapsis, in meters.
+    pub periapsis: f64,
+    pub eccentricity: f64,
+    pub inclination: f64,
+    pub longitude_of_ascending_node: f64,
+    pub argument_of_periapsis: f64,
+    /// The mean anomaly at `epoch`. For a parabolic orbit this is the parabolic mean anomaly
+    /// of Barker's equation, `D + D³/3` where `D = tan(ν/2)`.
+    pub mean_anomaly_at_epoch: f64,
+    /// The time at which the mean anomaly is `mean_anomaly_at_epoch`.
+    pub epoch: f64,
+}
+
+impl OrbitalElements {
+    /// Returns `true` if the orbit is exactly parabolic.
+    pub fn is_parabolic(&self) -> bool {
+        self.eccentricity == 1.0
+    }
+
+    /// Returns `true` if the orbit is hyperbolic.
+    pub fn is_hyperbolic(&self) -> bool {
+        self.eccentricity > 1.0
+    }
+
+    /// Returns the semi-major axis, in meters. It is negative for a hyperbolic orbit and
+    /// infinite for a parabolic one.
+    pub fn semi_major_axis(&self) -> f64 {
+        if self.is_parabolic() {
+            f64::INFINITY
+        } else {
+            self.periapsis / (1.0 - self.eccentricity)
+        }
+    }
+
+    /// Returns the semi-latus rectum, in meters.
+    pub fn semi_latus_rectum(&self) -> f64 {
+        self.periapsis * (1.0 + self.eccentricity)
+    }
+}
+
+/// The position and velocity of an orbiting object, relative to the center of the body it
+/// orbits.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateVector {
+    /// The position, in meters.
+    pub position: Vector3,
+    /// The velocity, in meters per second.
+    pub velocity: Vector3,
+}
@@ -0,0 +1,330 @@
+//! Maneuver nodes for common burns, ready to add with `ManeuverNode::add`.
+use super::{KeplerOrbit, StateVector};
+use crate::client::{decode_batch_result, Connection, KrpcResult};
+use crate::spacecenter::{Control, Node, Orbit, ReferenceFrame};
+use crate::Vector3;
+
+use std::f64::consts::PI;
+
+/// The number of times the burn time of a transfer is refined, after a first guess that
+/// assumes circular orbits.
+const REFINEMENTS: usize = 4;
+
+/// The most orbits of the vessel that a transfer may wait for its departure window. The
+/// window comes round once per synodic period, which grows without bound as the periods of
+/// the two orbits approach each other.
+const MAX_TRANSFER_WAIT: f64 = 100.0;
+
+/// The parameters of a maneuver node, as taken by `Control::add_node`. The delta-v components
+/// are in meters per second.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManeuverNode {
+    /// The universal time of the burn.
+    pub ut: f64,
+    /// The delta-v along the velocity.
+    pub prograde: f64,
+    /// The delta-v along the orbit normal, which points north for a prograde orbit.
+    pub normal: f64,
+    /// The delta-v away from the body, perpendicular to the velocity.
+    pub radial: f64,
+}
+
+impl ManeuverNode {
+    /// Returns the magnitude of the delta-v of the burn, in meters per second.
+    pub fn delta_v(&self) -> f64 {
+        Vector3::new(self.prograde, self.normal, self.radial).magnitude()
+    }
+
+    /// Adds the node to the flight plan of a vessel.
+    pub fn add<'a>(&self, control: &'a Control) -> KrpcResult<Node<'a>> {
+        control.add_node(
+            self.ut,
+            self.prograde as f32,
+            self.normal as f32,
+            self.radial as f32,
+        )
+    }
+}
+
+impl KeplerOrbit {
+    /// Returns the maneuver node for a burn at `ut` that changes the velocity to `velocity`.
+    pub fn maneuver_to_velocity(&self, ut: f64, velocity: Vector3) -> ManeuverNode {
+        let state = self.state_at(ut);
+        let delta_v = velocity - state.velocity;
+        let [prograde, normal, radial] = burn_axes(&state);
+        ManeuverNode {
+            ut,
+            prograde: delta_v.dot(prograde),
+            normal: delta_v.dot(normal),
+            radial: delta_v.dot(radial),
+        }
+    }
+
+    /// Returns the change in velocity made by the burn of `node`, in the axes of the orbit.
+    pub fn maneuver_delta_v(&self, node: &ManeuverNode) -> Vector3 {
+        let [prograde, normal, radial] = burn_axes(&self.state_at(node.ut));
+        prograde * node.prograde + normal * node.normal + radial * node.radial
+    }
+
+    /// Returns the orbit after the burn of `node`, treating it as instantaneous. Returns `None`
+    /// if the burn leaves no angular momentum.
+    pub fn after_maneuver(&self, node: &ManeuverNode) -> Option<KeplerOrbit> {
+        let state = self.state_at(node.ut);
+        let burned = StateVector {
+            velocity: state.velocity + self.maneuver_delta_v(node),
+            ..state
+        };
+        self.with_state_vector(&burned, node.ut)
+    }
+}
+
+/// Returns the unit vectors along the prograde, normal and radial directions.
+fn burn_axes(state: &StateVector) -> [Vector3; 3] {
+    // Reference frames are left-handed, so the orbit normal is `v × r`.
+    let prograde = state.velocity.normalize();
+    let normal = state.velocity.cross(state.position).normalize();
+    [prograde, normal, normal.cross(prograde)]
+}
+
+/// Returns the unit vector perpendicular to the position, in the plane of the orbit and in
+/// the direction of motion.
+fn horizontal(state: &StateVector) -> Vector3 {
+    let up = state.position.normalize();
+    (state.velocity - up * state.velocity.dot(up)).normalize()
+}
+
+/// Returns the angle from `from` to `to`, in radians in the range `[0, 2π)`, measured in the
+/// direction of motion of an orbit whose `r × v` is along the unit vector `axis`.
+fn angle_ahead(from: Vector3, to: Vector3, axis: Vector3) -> f64 {
+    axis.dot(from.cross(to))
+        .atan2(from.dot(to))
+        .rem_euclid(2.0 * PI)
+}
+
+/// Returns the node that makes the orbit circular at `ut`.
+pub fn circularize_at(orbit: &KeplerOrbit, ut: f64) -> ManeuverNode {
+    let state = orbit.state_at(ut);
+    let speed = (orbit.gravitational_parameter / state.position.magnitude()).sqrt();
+    orbit.maneuver_to_velocity(ut, horizontal(&state) * speed)
+}
+
+/// Returns the node that makes the orbit circular at the next apoapsis after `ut`, or `None`
+/// if the orbit has no apoapsis.
+pub fn circularize_at_apoapsis(orbit: &KeplerOrbit, ut: f64) -> Option<ManeuverNode> {
+    if orbit.is_closed() {
+        Some(circularize_at(orbit, orbit.ut_at_true_anomaly(PI, ut)))
+    } else {
+        None
+    }
+}
+
+/// Returns the node that makes the orbit circular at the next periapsis after `ut`.
+pub fn circularize_at_periapsis(orbit: &KeplerOrbit, ut: f64) -> ManeuverNode {
+    circularize_at(orbit, orbit.ut_at_true_anomaly(0.0, ut))
+}
+
+/// Returns a prograde or retrograde burn at `ut` that puts the opposite side of the orbit at
+/// `radius` from the center of the body. The orbit must be at an apsis at `ut`.
+fn burn_at_apsis(orbit: &KeplerOrbit, ut: f64, radius: f64) -> ManeuverNode {
+    let state = orbit.state_at(ut);
+    let r = state.position.magnitude();
+    let speed = (orbit.gravitational_parameter * (2.0 / r - 2.0 / (r + radius))).sqrt();
+    ManeuverNode {
+        ut,
+        prograde: speed - state.velocity.magnitude(),
+        ..ManeuverNode::default()
+    }
+}
+
+/// Returns the node at the next periapsis after `ut` that moves the apoapsis to `apoapsis`,
+/// measured from the center of the body, in meters.
+pub fn change_apoapsis(orbit: &KeplerOrbit, ut: f64, apoapsis: f64) -> ManeuverNode {
+    burn_at_apsis(orbit, orbit.ut_at_true_anomaly(0.0, ut), apoapsis)
+}
+
+/// Returns the node at the next apoapsis after `ut` that moves the periapsis to `periapsis`,
+/// measured from the center of the body, in meters. Returns `None` if the orbit has no
+/// apoapsis.
+pub fn change_periapsis(orbit: &KeplerOrbit, ut: f64, periapsis: f64) -> Option<ManeuverNode> {
+    if orbit.is_closed() {
+        Some(burn_at_apsis(
+            orbit,
+            orbit.ut_at_true_anomaly(PI, ut),
+            periapsis,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Returns the two nodes of a Hohmann transfer to `target`, departing at the first chance
+/// after `ut`: the burn onto the transfer orbit, and the burn that circularizes at the target.
+///
+/// Both orbits are assumed to be close to circular and in the same plane, so match planes
+/// first if they are not. Both must be around the same body and in the same axes.
+///
+/// Returns `None` if the orbits have so nearly the same period that the departure window is
+/// more than 100 orbits away, or never comes, such as when the target is ahead in the same
+/// orbit. A phasing orbit is needed to catch up with it instead.
+pub fn hohmann_transfer(
+    orbit: &KeplerOrbit,
+    target: &KeplerOrbit,
+    ut: f64,
+) -> Option<[ManeuverNode; 2]> {
+    let mu = orbit.gravitational_parameter;
+    let r1 = orbit.semi_major_axis();
+    let r2 = target.semi_major_axis();
+    let transfer_time = |r: f64| PI * ((r + r2).powi(3) / (8.0 * mu)).sqrt();
+    let axis = {
+        let state = orbit.state_at(ut);
+        state.position.cross(state.velocity).normalize()
+    };
+
+    // The angle the target must lead by at departure, so that it reaches the far side of the
+    // transfer orbit at the same time as the vessel. It closes at the difference of the mean
+    // motions.
+    let lead = |t: f64, r: f64| {
+        let phase = angle_ahead(orbit.position_at(t), target.position_at(t), axis);
+        let wanted = PI - target.mean_motion() * transfer_time(r);
+        (phase - wanted).rem_euclid(2.0 * PI)
+    };
+    let closing = orbit.mean_motion() - target.mean_motion();
+    if 2.0 * PI / closing.abs() > MAX_TRANSFER_WAIT * orbit.period() {
+        return None;
+    }
+    let mut departure = if closing > 0.0 {
+        ut + lead(ut, r1) / closing
+    } else {
+        ut + (2.0 * PI - lead(ut, r1)) / -closing
+    };
+    for _ in 0..REFINEMENTS {
+        let radius = orbit.radius_at(departure);
+        let error = (lead(departure, radius) + PI).rem_euclid(2.0 * PI) - PI;
+        departure += error / closing;
+    }
+
+    let state = orbit.state_at(departure);
+    let r = state.position.magnitude();
+    let speed = (mu * (2.0 / r - 2.0 / (r + r2))).sqrt();
+    let first = orbit.maneuver_to_velocity(departure, horizontal(&state) * speed);
+    let second = match orbit.after_maneuver(&first) {
+        Some(transfer) => circularize_at(&transfer, departure + transfer_time(r)),
+        None => ManeuverNode::default(),
+    };
+    Some([first, second])
+}
+
+/// Returns the node at the given true anomaly of `orbit`, the next time it is reached after
+/// `ut`, that turns the orbit into the plane of `target`. The true anomaly should be that of
+/// the ascending or descending node with the target. The burn keeps the speed and the
+/// flight path angle.
+pub fn match_planes_at(
+    orbit: &KeplerOrbit,
+    target: &KeplerOrbit,
+    true_anomaly: f64,
+    ut: f64,
+) -> ManeuverNode {
+    let ut = orbit.ut_at_true_anomaly(true_anomaly, ut);
+    let state = orbit.state_at(ut);
+    let target_state = target.state_at(ut);
+    let target_axis = target_state
+        .position
+        .cross(target_state.velocity)
+        .normalize();
+
+    let up = state.position.normalize();
+    let vertical = state.velocity.dot(up);
+    let horizontal = (state.velocity - up * vertical).magnitude();
+    let velocity = up * vertical + target_axis.cross(up) * horizontal;
+    orbit.maneuver_to_velocity(ut, velocity)
+}
+
+/// Returns the node that turns `orbit` into the plane of `target`, at whichever of the
+/// ascending and descending nodes comes first after `ut`. The nodes are found with
+/// `Orbit::true_anomaly_at_an` and `Orbit::true_anomaly_at_dn`, and positions and velocities
+/// are in the axes of `reference_frame`, as for `KeplerOrbit::fetch`.
+pub fn match_planes(
+    connection: &Connection,
+    orbit: &Orbit,
+    target: &Orbit,
+    reference_frame: &ReferenceFrame,
+    ut: f64,
+) -> KrpcResult<ManeuverNode> {
+    let local = KeplerOrbit::fetch(connection, orbit, reference_frame)?;
+    let local_target = KeplerOrbit::fetch(connection, target, reference_frame)?;
+
+    let calls = orbit.call();
+    let batch = vec![
+        calls.true_anomaly_at_an(target)?,
+        calls.true_anomaly_at_dn(target)?,
+    ];
+    let results = &mut connection.invoke_batch(batch)?.into_iter();
+    let ascending: f64 =
+        decode_batch_result(results, connection, "SpaceCenter", "Orbit_TrueAnomalyAtAN")?;
+    let descending: f64 =
+        decode_batch_result(results, connection, "SpaceCenter", "Orbit_TrueAnomalyAtDN")?;
+
+    let true_anomaly =
+        if local.ut_at_true_anomaly(ascending, ut) <= local.ut_at_true_anomaly(descending, ut) {
+            ascending
+        } else {
+            descending
+        };
+    Ok(match_planes_at(&local, &local_target, true_anomaly, ut))
+}
+
+/// Returns the node that leaves a moon, at the first chance after `ut`, on an orbit of its
+/// parent body with the given periapsis, measured from the center of the parent body.
+///
+/// `orbit` is the orbit around the moon, assumed to be close to circular, and `moon` is the
+/// orbit of the moon around its parent. Both must be in axes with the same orientation, such
+/// as the non-rotating reference frames of the two bodies. The escape is aimed along the
+/// velocity of the moon at the time of the burn, so a small correction may be needed once out
+/// of its sphere of influence.
+pub fn return_from_moon(
+    orbit: &KeplerOrbit,
+    moon: &KeplerOrbit,
+    periapsis: f64,
+    ut: f64,
+) -> ManeuverNode {
+    let mu = orbit.gravitational_parameter;
+    let axis = {
+        let state = orbit.state_at(ut);
+        state.position.cross(state.velocity).normalize()
+    };
+
+    // The speed left over after escaping, opposite to the velocity of the moon, so that the
+    // orbit around the parent has its apoapsis at the moon and its periapsis where wanted.
+    let escape = |t: f64| {
+        let moon_state = moon.state_at(t);
+        let distance = moon_state.position.magnitude();
+        let speed = moon_state.velocity.magnitude();
+        let apoapsis_speed = (moon.gravitational_parameter * 2.0 * periapsis
+            / (distance * (distance + periapsis)))
+            .sqrt();
+        (-moon_state.velocity / speed, speed - apoapsis_speed)
+    };
+
+    let mut burn = ut;
+    for _ in 0..REFINEMENTS {
+        let (direction, excess) = escape(burn);
+        let radius = orbit.radius_at(burn);
+
+        // The burn is at the periapsis of the escape hyperbola, behind its outgoing asymptote
+        // by the true anomaly of the asymptote.
+        let eccentricity = 1.0 + radius * excess * excess / mu;
+        let asymptote = (-1.0 / eccentricity).acos();
+        let direction = (direction - axis * direction.dot(axis)).normalize();
+        let periapsis_direction =
+            direction * asymptote.cos() - axis.cross(direction) * asymptote.sin();
+
+        let ahead = angle_ahead(orbit.position_at(ut), periapsis_direction, axis);
+        burn = orbit.ut_at_true_anomaly(orbit.true_anomaly_at(ut) + ahead, ut);
+    }
+
+    let (_, excess) = escape(burn);
+    let state = orbit.state_at(burn);
+    let speed = (excess * excess + 2.0 * mu / state.position.magnitude()).sqrt();
+    orbit.maneuver_to_velocity(burn, horizontal(&state) * speed)
+}
@@ -0,0 +1,19 @@
+//! Keplerian orbits computed on the client.
+//!
+//! A `KeplerOrbit` is a snapshot of the elements of an `Orbit`, which can then be propagated
+//! to any time without calling the server. It ignores everything but the gravity of the body
+//! being orbited, like the game itself does, so it stays exact until the orbit is changed by
+//! thrust, drag or a sphere of influence change.
+//!
+//! Orbits can also be built from a position and velocity, with
+//! `KeplerOrbit::from_state_vector`, to work out the orbit after a burn before adding a node
+//! for it. The `maneuver` module plans the nodes for common burns.
+
+mod elements;
+pub mod maneuver;
+mod propagator;
+mod state;
+
+pub use self::elements::*;
+pub use self::maneuver::ManeuverNode;
+pub use self::propagator::*;
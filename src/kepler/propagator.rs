@@ -0,0 +1,350 @@
+use super::{OrbitalElements, StateVector};
+use crate::client::{decode_space_center_result, Connection, KrpcResult};
+use crate::spacecenter::{Orbit, OrbitCall, ReferenceFrame};
+use crate::Vector3;
+
+use std::f64::consts::PI;
+
+/// The number of Newton iterations after which solving Kepler's equation gives up improving
+/// the result. Convergence normally takes fewer than ten.
+const MAX_ITERATIONS: usize = 50;
+
+/// An orbit around a body, propagated on the client.
+///
+/// Positions and velocities are relative to the center of the body, in the axes given by
+/// `reference_direction` and `reference_normal`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeplerOrbit {
+    pub elements: OrbitalElements,
+    /// The standard gravitational parameter of the body, in m<sup>3</sup>/s<sup>2</sup>.
+    pub gravitational_parameter: f64,
+    /// The direction from which the longitude of the ascending node is measured, as a unit
+    /// vector.
+    pub reference_direction: Vector3,
+    /// The normal to the plane from which the inclination is measured, pointing north, as a
+    /// unit vector.
+    pub reference_normal: Vector3,
+}
+
+impl KeplerOrbit {
+    /// Creates an orbit whose positions and velocities are in the axes of the non-rotating
+    /// reference frame of the body, where the reference direction is the x axis and the north
+    /// pole is along the y axis.
+    pub fn new(elements: OrbitalElements, gravitational_parameter: f64) -> Self {
+        KeplerOrbit {
+            elements,
+            gravitational_parameter,
+            reference_direction: Vector3::new(1.0, 0.0, 0.0),
+            reference_normal: Vector3::new(0.0, 1.0, 0.0),
+        }
+    }
+
+    /// Fetches a snapshot of `orbit` in a single request. Positions and velocities are in the
+    /// axes of `reference_frame`, which should not rotate, such as the non-rotating reference
+    /// frame of the body.
+    pub fn fetch(
+        connection: &Connection,
+        orbit: &Orbit,
+        reference_frame: &ReferenceFrame,
+    ) -> KrpcResult<Self> {
+        let calls = orbit.call();
+        let batch = vec![
+            calls.periapsis()?,
+            calls.eccentricity()?,
+            calls.inclination()?,
+            calls.longitude_of_ascending_node()?,
+            calls.argument_of_periapsis()?,
+            calls.mean_anomaly_at_epoch()?,
+            calls.epoch()?,
+            orbit.body()?.call().gravitational_parameter()?,
+            OrbitCall::reference_plane_direction(connection, reference_frame)?,
+            OrbitCall::reference_plane_normal(connection, reference_frame)?,
+        ];
+
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let elements = OrbitalElements {
+            periapsis: decode_space_center_result(results, connection, "Orbit_get_Periapsis")?,
+            eccentricity: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_get_Eccentricity",
+            )?,
+            inclination: decode_space_center_result(results, connection, "Orbit_get_Inclination")?,
+            longitude_of_ascending_node: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_get_LongitudeOfAscendingNode",
+            )?,
+            argument_of_periapsis: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_get_ArgumentOfPeriapsis",
+            )?,
+            mean_anomaly_at_epoch: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_get_MeanAnomalyAtEpoch",
+            )?,
+            epoch: decode_space_center_result(results, connection, "Orbit_get_Epoch")?,
+        };
+        let gravitational_parameter: f32 = decode_space_center_result(
+            results,
+            connection,
+            "CelestialBody_get_GravitationalParameter",
+        )?;
+
+        Ok(KeplerOrbit {
+            elements,
+            gravitational_parameter: f64::from(gravitational_parameter),
+            reference_direction: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_static_ReferencePlaneDirection",
+            )?,
+            reference_normal: decode_space_center_result(
+                results,
+                connection,
+                "Orbit_static_ReferencePlaneNormal",
+            )?,
+        })
+    }
+
+    /// Returns `true` if the orbit is elliptical, and so returns to its periapsis.
+    pub fn is_closed(&self) -> bool {
+        self.elements.eccentricity < 1.0
+    }
+
+    /// Returns `true` if the orbit is exactly parabolic.
+    pub fn is_parabolic(&self) -> bool {
+        self.elements.is_parabolic()
+    }
+
+    /// Returns `true` if the orbit is hyperbolic.
+    pub fn is_hyperbolic(&self) -> bool {
+        self.elements.is_hyperbolic()
+    }
+
+    /// Returns the semi-major axis, in meters. It is negative for a hyperbolic orbit and
+    /// infinite for a parabolic one.
+    pub fn semi_major_axis(&self) -> f64 {
+        self.elements.semi_major_axis()
+    }
+
+    /// Returns the semi-latus rectum, in meters.
+    pub fn semi_latus_rectum(&self) -> f64 {
+        self.elements.semi_latus_rectum()
+    }
+
+    /// Returns the distance from the center of the body to the periapsis, in meters.
+    pub fn periapsis(&self) -> f64 {
+        self.elements.periapsis
+    }
+
+    /// Returns the distance from the center of the body to the apoapsis, in meters, or
+    /// infinity if the orbit is not closed.
+    pub fn apoapsis(&self) -> f64 {
+        if self.is_closed() {
+            self.semi_major_axis() * (1.0 + self.elements.eccentricity)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Returns the mean motion, the average angular speed, in radians per second. For a
+    /// parabolic orbit it is the rate of change of the parabolic mean anomaly.
+    pub fn mean_motion(&self) -> f64 {
+        let mu = self.gravitational_parameter;
+        if self.is_parabolic() {
+            (mu / (2.0 * self.elements.periapsis.powi(3))).sqrt()
+        } else {
+            (mu / self.semi_major_axis().abs().powi(3)).sqrt()
+        }
+    }
+
+    /// Returns the orbital period, in seconds, or infinity if the orbit is not closed.
+    pub fn period(&self) -> f64 {
+        if !self.is_closed() {
+            f64::INFINITY
+        } else {
+            2.0 * PI / self.mean_motion()
+        }
+    }
+
+    /// Returns the mean anomaly at the given universal time. For an elliptical orbit it is in
+    /// the range `[0, 2π)`.
+    pub fn mean_anomaly_at(&self, ut: f64) -> f64 {
+        let mean_anomaly =
+            self.elements.mean_anomaly_at_epoch + self.mean_motion() * (ut - self.elements.epoch);
+        if self.is_closed() {
+            mean_anomaly.rem_euclid(2.0 * PI)
+        } else {
+            mean_anomaly
+        }
+    }
+
+    /// Returns the eccentric anomaly at the given universal time, the hyperbolic anomaly for a
+    /// hyperbolic orbit or the parabolic anomaly `D = tan(ν/2)` for a parabolic one.
+    pub fn eccentric_anomaly_at(&self, ut: f64) -> f64 {
+        eccentric_anomaly(self.mean_anomaly_at(ut), self.elements.eccentricity)
+    }
+
+    /// Returns the true anomaly at the given universal time. For an elliptical orbit it is in
+    /// the range `[0, 2π)`, and otherwise it is negative before the periapsis.
+    pub fn true_anomaly_at(&self, ut: f64) -> f64 {
+        let e = self.elements.eccentricity;
+        let anomaly = self.eccentric_anomaly_at(ut);
+        if self.is_parabolic() {
+            2.0 * anomaly.atan()
+        } else if self.is_hyperbolic() {
+            2.0 * (((e + 1.0) / (e - 1.0)).sqrt() * (anomaly / 2.0).tanh()).atan()
+        } else {
+            let (sin, cos) = (anomaly / 2.0).sin_cos();
+            (2.0 * ((1.0 + e).sqrt() * sin).atan2((1.0 - e).sqrt() * cos)).rem_euclid(2.0 * PI)
+        }
+    }
+
+    /// Returns the first universal time at or after `ut` at which the orbit passes through the
+    /// given true anomaly. An orbit that is not closed passes through it only once, which may
+    /// be before `ut`.
+    pub fn ut_at_true_anomaly(&self, true_anomaly: f64, ut: f64) -> f64 {
+        let mean_anomaly = mean_anomaly(true_anomaly, self.elements.eccentricity);
+        if self.is_closed() {
+            let remaining = (mean_anomaly - self.mean_anomaly_at(ut)).rem_euclid(2.0 * PI);
+            ut + remaining / self.mean_motion()
+        } else {
+            self.elements.epoch
+                + (mean_anomaly - self.elements.mean_anomaly_at_epoch) / self.mean_motion()
+        }
+    }
+
+    /// Returns the distance from the center of the body at the given universal time, in meters.
+    pub fn radius_at(&self, ut: f64) -> f64 {
+        self.radius_at_true_anomaly(self.true_anomaly_at(ut))
+    }
+
+    /// Returns the distance from the center of the body at the given true anomaly, in meters.
+    pub fn radius_at_true_anomaly(&self, true_anomaly: f64) -> f64 {
+        self.semi_latus_rectum() / (1.0 + self.elements.eccentricity * true_anomaly.cos())
+    }
+
+    /// Returns the position at the given universal time, in meters.
+    pub fn position_at(&self, ut: f64) -> Vector3 {
+        self.position_at_true_anomaly(self.true_anomaly_at(ut))
+    }
+
+    /// Returns the velocity at the given universal time, in meters per second.
+    pub fn velocity_at(&self, ut: f64) -> Vector3 {
+        self.velocity_at_true_anomaly(self.true_anomaly_at(ut))
+    }
+
+    /// Returns the position and velocity at the given universal time.
+    pub fn state_at(&self, ut: f64) -> StateVector {
+        let true_anomaly = self.true_anomaly_at(ut);
+        StateVector {
+            position: self.position_at_true_anomaly(true_anomaly),
+            velocity: self.velocity_at_true_anomaly(true_anomaly),
+        }
+    }
+
+    /// Returns the position at the given true anomaly, in meters.
+    pub fn position_at_true_anomaly(&self, true_anomaly: f64) -> Vector3 {
+        let (sin, cos) = true_anomaly.sin_cos();
+        let radius = self.radius_at_true_anomaly(true_anomaly);
+        self.perifocal_to_reference(radius * cos, radius * sin)
+    }
+
+    /// Returns the velocity at the given true anomaly, in meters per second.
+    pub fn velocity_at_true_anomaly(&self, true_anomaly: f64) -> Vector3 {
+        let (sin, cos) = true_anomaly.sin_cos();
+        let speed = (self.gravitational_parameter / self.semi_latus_rectum()).sqrt();
+        self.perifocal_to_reference(-speed * sin, speed * (self.elements.eccentricity + cos))
+    }
+
+    /// Converts a vector in the plane of the orbit, with x towards the periapsis, to the axes of
+    /// the reference frame.
+    fn perifocal_to_reference(&self, x: f64, y: f64) -> Vector3 {
+        let OrbitalElements {
+            inclination: i,
+            longitude_of_ascending_node: lan,
+            argument_of_periapsis: argp,
+            ..
+        } = self.elements;
+        let (sin_lan, cos_lan) = lan.sin_cos();
+        let (sin_argp, cos_argp) = argp.sin_cos();
+        let (sin_i, cos_i) = i.sin_cos();
+
+        // The usual right-handed rotation to the reference plane, with z to the north.
+        let p = (
+            cos_lan * cos_argp - sin_lan * sin_argp * cos_i,
+            sin_lan * cos_argp + cos_lan * sin_argp * cos_i,
+            sin_argp * sin_i,
+        );
+        let q = (
+            -cos_lan * sin_argp - sin_lan * cos_argp * cos_i,
+            -sin_lan * sin_argp + cos_lan * cos_argp * cos_i,
+            cos_argp * sin_i,
+        );
+        let (rx, ry, rz) = (x * p.0 + y * q.0, x * p.1 + y * q.1, x * p.2 + y * q.2);
+
+        // kRPC reference frames are left-handed, so the axis 90° ahead of the reference
+        // direction in the reference plane is `direction × normal`.
+        let direction = self.reference_direction;
+        let normal = self.reference_normal;
+        direction * rx + direction.cross(normal) * ry + normal * rz
+    }
+}
+
+/// Solves Kepler's equation for the eccentric anomaly of an elliptical orbit, the hyperbolic
+/// anomaly of a hyperbolic orbit, or Barker's equation for the parabolic anomaly of a parabolic
+/// orbit.
+pub fn eccentric_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let (m, e) = (mean_anomaly, eccentricity);
+    if e == 1.0 {
+        // M = D + D³/3, solved in closed form. The root is odd in M, and solving for |M|
+        // avoids the cancellation in the cube root for negative M.
+        let w = 1.5 * m.abs();
+        let y = (w + (w * w + 1.0).sqrt()).cbrt();
+        (y - 1.0 / y).copysign(m)
+    } else if e < 1.0 {
+        // M = E - e sin E
+        let mut anomaly = if e < 0.8 { m } else { PI };
+        for _ in 0..MAX_ITERATIONS {
+            let step = (anomaly - e * anomaly.sin() - m) / (1.0 - e * anomaly.cos());
+            anomaly -= step;
+            if step.abs() <= 1e-15 * anomaly.abs().max(1.0) {
+                break;
+            }
+        }
+        anomaly
+    } else {
+        // M = e sinh H - H, starting from Danby's guess.
+        let mut anomaly = (2.0 * m.abs() / e + 1.8).ln().copysign(m);
+        for _ in 0..MAX_ITERATIONS {
+            let step = (e * anomaly.sinh() - anomaly - m) / (e * anomaly.cosh() - 1.0);
+            anomaly -= step;
+            if step.abs() <= 1e-15 * anomaly.abs().max(1.0) {
+                break;
+            }
+        }
+        anomaly
+    }
+}
+
+/// Returns the mean anomaly at the given true anomaly, the inverse of solving Kepler's equation.
+/// For an elliptical orbit it is in the range `[0, 2π)`.
+pub fn mean_anomaly(true_anomaly: f64, eccentricity: f64) -> f64 {
+    let (half, e) = (true_anomaly / 2.0, eccentricity);
+    if e == 1.0 {
+        // Barker's equation.
+        let d = half.tan();
+        d + d * d * d / 3.0
+    } else if e < 1.0 {
+        let (sin, cos) = half.sin_cos();
+        let anomaly = 2.0 * ((1.0 - e).sqrt() * sin).atan2((1.0 + e).sqrt() * cos);
+        (anomaly - e * anomaly.sin()).rem_euclid(2.0 * PI)
+    } else {
+        let anomaly = 2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * half.tan()).atanh();
+        e * anomaly.sinh() - anomaly
+    }
+}
@@ -0,0 +1,153 @@
+use super::{mean_anomaly, KeplerOrbit, OrbitalElements, StateVector};
+use crate::Vector3;
+
+use std::f64::consts::PI;
+
+/// Eccentricities this close to 1 are rounded to exactly 1, since the propagator can only
+/// represent them accurately as a parabola.
+const PARABOLIC_TOLERANCE: f64 = 1e-9;
+
+/// Eccentricities below this are treated as circular, placing the periapsis at the ascending
+/// node.
+const CIRCULAR_TOLERANCE: f64 = 1e-11;
+
+/// Orbits whose ascending node, relative to the angular momentum, is shorter than this are
+/// treated as equatorial, measuring the periapsis from the reference direction.
+const EQUATORIAL_TOLERANCE: f64 = 1e-11;
+
+impl OrbitalElements {
+    /// Computes the elements of the orbit through `state` at the universal time `ut`, around a
+    /// body with the given gravitational parameter. The state is in the axes of the
+    /// non-rotating reference frame of the body.
+    ///
+    /// For an equatorial orbit the longitude of the ascending node is zero, and for a circular
+    /// orbit the argument of periapsis is zero. Returns `None` if the state has no angular
+    /// momentum, such as when falling straight down.
+    pub fn from_state_vector(
+        state: &StateVector,
+        ut: f64,
+        gravitational_parameter: f64,
+    ) -> Option<Self> {
+        KeplerOrbit::from_state_vector(state, ut, gravitational_parameter)
+            .map(|orbit| orbit.elements)
+    }
+
+    /// Returns the position and velocity at the universal time `ut`, in the axes of the
+    /// non-rotating reference frame of the body. This is the inverse of `from_state_vector`.
+    pub fn state_vector_at(&self, ut: f64, gravitational_parameter: f64) -> StateVector {
+        KeplerOrbit::new(*self, gravitational_parameter).state_at(ut)
+    }
+}
+
+impl KeplerOrbit {
+    /// Creates the orbit through `state` at the universal time `ut`, in the axes of the
+    /// non-rotating reference frame of the body, as for `new`. Returns `None` if the state has
+    /// no angular momentum.
+    pub fn from_state_vector(
+        state: &StateVector,
+        ut: f64,
+        gravitational_parameter: f64,
+    ) -> Option<Self> {
+        let direction = Vector3::new(1.0, 0.0, 0.0);
+        let normal = Vector3::new(0.0, 1.0, 0.0);
+        elements(state, ut, gravitational_parameter, direction, normal)
+            .map(|elements| KeplerOrbit::new(elements, gravitational_parameter))
+    }
+
+    /// Returns the orbit through `state` at the universal time `ut`, around the same body and
+    /// in the same axes as this one. Returns `None` if the state has no angular momentum.
+    ///
+    /// This gives the orbit after an impulsive burn without asking the server:
+    ///
+    /// ```no_run
+    /// # use krpc_bindings::kepler::{KeplerOrbit, StateVector};
+    /// # use krpc_bindings::Vector3;
+    /// # fn f(orbit: KeplerOrbit, ut: f64, delta_v: Vector3) -> Option<f64> {
+    /// let state = orbit.state_at(ut);
+    /// let burned = StateVector {
+    ///     velocity: state.velocity + delta_v,
+    ///     ..state
+    /// };
+    /// let after = orbit.with_state_vector(&burned, ut)?;
+    /// Some(after.apoapsis())
+    /// # }
+    /// ```
+    pub fn with_state_vector(&self, state: &StateVector, ut: f64) -> Option<Self> {
+        let elements = elements(
+            state,
+            ut,
+            self.gravitational_parameter,
+            self.reference_direction,
+            self.reference_normal,
+        )?;
+        Some(KeplerOrbit { elements, ..*self })
+    }
+}
+
+/// Computes the elements of the orbit through `state` at `ut`, with positions and velocities
+/// in the axes given by the reference direction and normal.
+fn elements(
+    state: &StateVector,
+    ut: f64,
+    mu: f64,
+    direction: Vector3,
+    normal: Vector3,
+) -> Option<OrbitalElements> {
+    // Work in right-handed coordinates with z to the north, the inverse of
+    // `perifocal_to_reference`.
+    let side = direction.cross(normal);
+    let to_plane = |v: Vector3| Vector3::new(v.dot(direction), v.dot(side), v.dot(normal));
+    let r = to_plane(state.position);
+    let v = to_plane(state.velocity);
+
+    let h = r.cross(v);
+    let h_magnitude = h.magnitude();
+    let radius = r.magnitude();
+    if h_magnitude.is_nan() || h_magnitude <= f64::EPSILON * radius * v.magnitude() {
+        return None;
+    }
+    let h_direction = h / h_magnitude;
+
+    let eccentricity_vector = (r * (v.magnitude_squared() - mu / radius) - v * r.dot(v)) / mu;
+    let mut e = eccentricity_vector.magnitude();
+    if (e - 1.0).abs() < PARABOLIC_TOLERANCE {
+        e = 1.0;
+    }
+
+    let inclination = h.x.hypot(h.y).atan2(h.z);
+    let node = Vector3::new(-h.y, h.x, 0.0);
+    let (longitude_of_ascending_node, node_direction) =
+        if node.magnitude() <= EQUATORIAL_TOLERANCE * h_magnitude {
+            (0.0, Vector3::new(1.0, 0.0, 0.0))
+        } else {
+            (node.y.atan2(node.x).rem_euclid(2.0 * PI), node.normalize())
+        };
+
+    // The in-plane direction 90° ahead of the ascending node.
+    let ahead = h_direction.cross(node_direction);
+    let argument_of_periapsis = if e < CIRCULAR_TOLERANCE {
+        0.0
+    } else {
+        eccentricity_vector
+            .dot(ahead)
+            .atan2(eccentricity_vector.dot(node_direction))
+            .rem_euclid(2.0 * PI)
+    };
+
+    let (sin, cos) = argument_of_periapsis.sin_cos();
+    let periapsis_direction = node_direction * cos + ahead * sin;
+    let true_anomaly = r
+        .dot(h_direction.cross(periapsis_direction))
+        .atan2(r.dot(periapsis_direction));
+
+    let semi_latus_rectum = h_magnitude * h_magnitude / mu;
+    Some(OrbitalElements {
+        periapsis: semi_latus_rectum / (1.0 + e),
+        eccentricity: e,
+        inclination,
+        longitude_of_ascending_node,
+        argument_of_periapsis,
+        mean_anomaly_at_epoch: mean_anomaly(true_anomaly, e),
+        epoch: ut,
+    })
+}
@@ -1,6 +1,5 @@
 use crate::client::ProcedureCall;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 use std::collections::BTreeMap;
 
@@ -321,31 +320,31 @@ object SpaceCenter.Type {
     static_methods: {
         {
             /// Double type.
-            fn double() -> Type {
+            fn double() -> Type<'a> {
                 Double()
             }
         }
         {
             /// Float type.
-            fn float() -> Type {
+            fn float() -> Type<'a> {
                 Float()
             }
         }
         {
             /// Int type.
-            fn int() -> Type {
+            fn int() -> Type<'a> {
                 Int()
             }
         }
         {
             /// Bool type.
-            fn bool() -> Type {
+            fn bool() -> Type<'a> {
                 Bool()
             }
         }
         {
             /// String type.
-            fn string() -> Type {
+            fn string() -> Type<'a> {
                 String()
             }
         }
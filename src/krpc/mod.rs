@@ -1,6 +1,6 @@
 use crate::client::{Services, Status};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum};
+use crate::codec::Bytes;
+use crate::remote_type;
 
 mod expression;
 
@@ -8,7 +8,7 @@ pub use self::expression::*;
 
 /// Type alias for client information.  First element is client identifier, second is name, and
 /// the third is address.
-pub type Client = (Vec<u8>, String, String);
+pub type Client = (Bytes, String, String);
 
 remote_type!(
     /// KRPC service.
@@ -49,7 +49,7 @@ remote_type!(
                 /// Returns the identifier for the current client.
                 ///
                 /// **Game Scenes**: All
-                fn client_id() -> Vec<u8> {
+                fn client_id() -> Bytes {
                     GetClientID()
                 }
             }
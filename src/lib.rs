@@ -1,21 +1,36 @@
 #[macro_use]
 extern crate failure;
-extern crate paste;
 extern crate protobuf;
 extern crate hex;
+extern crate png;
+#[cfg(feature = "serde")]
+extern crate serde;
+extern crate krpc_bindings_macros;
 
+// Lets the paths generated by `remote_type!` resolve inside this crate as well.
+extern crate self as krpc_bindings;
+
+pub mod alarm;
 pub mod client;
+pub mod dockingcamera;
 pub mod drawing;
+pub mod guidance;
 pub mod infernalrobotics;
 pub mod kac;
+pub mod kepler;
 pub mod krpc;
+pub mod lidar;
+pub mod math;
+pub mod mechjeb;
 pub mod remotetech;
 pub mod spacecenter;
+pub mod staging;
 pub mod ui;
-#[macro_use]
-mod macros;
 pub mod codec;
 
+pub use krpc_bindings_macros::remote_type;
+pub use math::{Quaternion, Vector3};
+
 pub trait RemoteObject<'a> {
     fn new(connection: &'a client::Connection, id: u64) -> Self
     where
@@ -31,8 +46,3 @@ pub trait RemoteEnum {
 
     fn value(&self) -> i64;
 }
-
-/// Type alias for a 3-dimension Vector.
-pub type Vector3 = (f64, f64, f64);
-/// Type alias for a Quaternion.
-pub type Quaternion = (f64, f64, f64, f64);
@@ -0,0 +1,27 @@
+use super::PointCloud;
+use crate::remote_type;
+use crate::spacecenter::Part;
+
+remote_type!(
+    /// A LiDAR sensor.
+    object LiDAR.Laser {
+        properties: {
+            {
+                Part {
+                    /// Returns the part object for this laser.
+                    ///
+                    /// **Game Scenes**: All
+                    get: part -> Part
+                }
+            }
+            {
+                Cloud {
+                    /// Returns the point cloud seen by the laser.
+                    ///
+                    /// **Game Scenes**: All
+                    get: cloud -> PointCloud
+                }
+            }
+        }
+    }
+);
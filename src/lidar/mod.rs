@@ -0,0 +1,37 @@
+use crate::remote_type;
+use crate::spacecenter::Part;
+
+mod laser;
+mod point_cloud;
+
+pub use self::laser::*;
+pub use self::point_cloud::*;
+
+remote_type!(
+    /// This service provides functionality to interact with LiDAR.
+    service LiDAR {
+        properties: {
+            {
+                Available {
+                    /// Returns whether LiDAR is installed.
+                    ///
+                    /// **Game Scenes**: All
+                    get: is_available -> bool
+                }
+            }
+        }
+        methods: {
+            {
+                /// Get a LiDAR part.
+                ///
+                /// **Game Scenes**: All
+                ///
+                /// # Arguments
+                /// `part` - The part that has a laser.
+                fn laser(part: &Part) -> Laser {
+                    Laser(part)
+                }
+            }
+        }
+    }
+);
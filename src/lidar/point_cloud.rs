@@ -0,0 +1,53 @@
+use crate::client::Connection;
+use crate::codec::{CodecError, CodecResult, Decode, Error};
+use crate::Vector3;
+
+/// The points seen by a LiDAR laser.
+///
+/// The server sends the coordinates of every point as one flat list of numbers, which is
+/// grouped into one `Vector3` per point.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointCloud(pub Vec<Vector3>);
+
+impl PointCloud {
+    /// Returns the points.
+    pub fn into_vec(self) -> Vec<Vector3> {
+        self.0
+    }
+}
+
+impl From<PointCloud> for Vec<Vector3> {
+    fn from(cloud: PointCloud) -> Self {
+        cloud.0
+    }
+}
+
+impl std::ops::Deref for PointCloud {
+    type Target = [Vector3];
+
+    fn deref(&self) -> &[Vector3] {
+        &self.0
+    }
+}
+
+impl<'a> Decode<'a> for PointCloud {
+    fn decode(bytes: &[u8], connection: &'a Connection) -> CodecResult<Self> {
+        let coordinates = Vec::<f64>::decode(bytes, connection)?;
+        if coordinates.len() % 3 != 0 {
+            return Err(Error::from(CodecError::Malformed {
+                type_name: "PointCloud",
+                reason: format!(
+                    "{} coordinates is not a whole number of points",
+                    coordinates.len()
+                ),
+            }));
+        }
+
+        Ok(PointCloud(
+            coordinates
+                .chunks_exact(3)
+                .map(|point| Vector3::new(point[0], point[1], point[2]))
+                .collect(),
+        ))
+    }
+}
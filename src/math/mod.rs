@@ -0,0 +1,12 @@
+//! Vector and quaternion types used by the kRPC procedures.
+//!
+//! Both types are encoded as a kRPC `Tuple` of their components, exactly like the
+//! `(f64, f64, f64)` and `(f64, f64, f64, f64)` tuples the server expects, and convert to and
+//! from those tuples. With the `nalgebra` or `glam` features they also convert to and from the
+//! corresponding types of those crates.
+
+mod quaternion;
+mod vector;
+
+pub use self::quaternion::Quaternion;
+pub use self::vector::Vector3;
@@ -0,0 +1,235 @@
+use super::Vector3;
+use crate::codec::{Decode, Encode};
+
+use std::ops::Mul;
+
+/// A quaternion representing a rotation, stored in kRPC's `(x, y, z, w)` order.
+///
+/// `a * b` is the rotation that applies `b` first and then `a`, and `q * v` rotates the vector
+/// `v` by `q`.
+#[derive(Debug, Copy, Clone, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Quaternion {
+    /// The rotation that leaves every vector unchanged.
+    pub const IDENTITY: Quaternion = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Quaternion { x, y, z, w }
+    }
+
+    /// Returns the rotation of `angle` radians about `axis`. The axis does not need to be
+    /// normalized.
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Quaternion {
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let axis = axis.normalize() * sin;
+        Quaternion::new(axis.x, axis.y, axis.z, cos)
+    }
+
+    /// Returns the rotation that takes the direction `from` onto the direction `to`, by the
+    /// smallest angle.
+    pub fn from_to(from: Vector3, to: Vector3) -> Quaternion {
+        let axis = from.cross(to);
+        if axis.magnitude_squared() > 0.0 {
+            return Quaternion::from_axis_angle(axis, from.angle_between(to));
+        }
+        if from.dot(to) >= 0.0 {
+            return Quaternion::IDENTITY;
+        }
+
+        // Opposite directions: turn half way around any axis perpendicular to `from`.
+        let mut perpendicular = from.cross(Vector3::new(1.0, 0.0, 0.0));
+        if perpendicular.magnitude_squared() == 0.0 {
+            perpendicular = from.cross(Vector3::new(0.0, 1.0, 0.0));
+        }
+        Quaternion::from_axis_angle(perpendicular, std::f64::consts::PI)
+    }
+
+    /// Returns the vector part `(x, y, z)` of the quaternion.
+    pub fn vector(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(self, other: Quaternion) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn magnitude(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the quaternion scaled to a length of 1. Only unit quaternions represent
+    /// rotations.
+    pub fn normalize(self) -> Quaternion {
+        let magnitude = self.magnitude();
+        Quaternion::new(
+            self.x / magnitude,
+            self.y / magnitude,
+            self.z / magnitude,
+            self.w / magnitude,
+        )
+    }
+
+    /// Returns the conjugate, which is the inverse rotation for a unit quaternion.
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse rotation.
+    pub fn inverse(self) -> Quaternion {
+        let magnitude_squared = self.dot(self);
+        let c = self.conjugate();
+        Quaternion::new(
+            c.x / magnitude_squared,
+            c.y / magnitude_squared,
+            c.z / magnitude_squared,
+            c.w / magnitude_squared,
+        )
+    }
+
+    /// Rotates `v` by this quaternion, which must be a unit quaternion.
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        let u = self.vector();
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+
+    /// Returns the axis of the rotation and its angle in radians, in the range `[0, π]`. The
+    /// identity rotation has no axis, and returns the zero vector.
+    pub fn axis_angle(self) -> (Vector3, f64) {
+        let q = if self.w < 0.0 {
+            self.normalize().negate()
+        } else {
+            self.normalize()
+        };
+        let sin = q.vector().magnitude();
+        (q.vector().normalize(), 2.0 * sin.atan2(q.w))
+    }
+
+    /// Returns the angle of the smallest rotation that takes this orientation onto `other`, in
+    /// radians, in the range `[0, π]`.
+    pub fn angle_between(self, other: Quaternion) -> f64 {
+        (other * self.inverse()).axis_angle().1
+    }
+
+    /// Spherically interpolates between the two rotations along the shortest path, returning
+    /// `self` when `t` is 0 and `other` when `t` is 1. Both must be unit quaternions.
+    pub fn slerp(self, other: Quaternion, t: f64) -> Quaternion {
+        // q and -q are the same rotation; pick the one on the near side to take the short way.
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            other.negate()
+        } else {
+            other
+        };
+
+        let (a, b) = if cos > 1.0 - 1e-9 {
+            // Nearly identical rotations: sin(θ) vanishes, so interpolate linearly instead.
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        Quaternion::new(
+            a * self.x + b * other.x,
+            a * self.y + b * other.y,
+            a * self.z + b * other.z,
+            a * self.w + b * other.w,
+        )
+        .normalize()
+    }
+
+    fn negate(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::IDENTITY
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for Quaternion {
+    fn from((x, y, z, w): (f64, f64, f64, f64)) -> Self {
+        Quaternion::new(x, y, z, w)
+    }
+}
+
+impl From<Quaternion> for (f64, f64, f64, f64) {
+    fn from(q: Quaternion) -> Self {
+        (q.x, q.y, q.z, q.w)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.rotate(rhs)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Quaternion> for nalgebra::Quaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        nalgebra::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Quaternion<f64>> for Quaternion {
+    fn from(q: nalgebra::Quaternion<f64>) -> Self {
+        Quaternion::new(q.i, q.j, q.k, q.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Quaternion> for nalgebra::UnitQuaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        nalgebra::UnitQuaternion::new_normalize(q.into())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::UnitQuaternion<f64>> for Quaternion {
+    fn from(q: nalgebra::UnitQuaternion<f64>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Quaternion> for glam::DQuat {
+    fn from(q: Quaternion) -> Self {
+        glam::DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DQuat> for Quaternion {
+    fn from(q: glam::DQuat) -> Self {
+        Quaternion::new(q.x, q.y, q.z, q.w)
+    }
+}
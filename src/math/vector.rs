@@ -0,0 +1,219 @@
+use crate::codec::{Decode, Encode};
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A 3-dimensional vector, such as a position, direction or velocity.
+///
+/// The components are in the axes of whichever reference frame the vector was obtained in.
+/// kRPC reference frames are left-handed, so `cross` follows the left-hand rule when applied
+/// to them.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    /// The zero vector.
+    pub const ZERO: Vector3 = Vector3::new(0.0, 0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    /// Returns the dot product of the two vectors.
+    pub fn dot(self, other: Vector3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of the two vectors.
+    pub fn cross(self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the length of the vector.
+    pub fn magnitude(self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Returns the squared length of the vector, which avoids a square root when only comparing
+    /// lengths.
+    pub fn magnitude_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the distance between the two points.
+    pub fn distance(self, other: Vector3) -> f64 {
+        (other - self).magnitude()
+    }
+
+    /// Returns a vector with the same direction and a length of 1. The zero vector is returned
+    /// unchanged, as it has no direction.
+    pub fn normalize(self) -> Vector3 {
+        let magnitude = self.magnitude();
+        if magnitude == 0.0 {
+            self
+        } else {
+            self / magnitude
+        }
+    }
+
+    /// Returns the angle between the two vectors, in radians, in the range `[0, π]`.
+    pub fn angle_between(self, other: Vector3) -> f64 {
+        // atan2 stays accurate for nearly parallel vectors, where acos of the dot product does not.
+        self.cross(other).magnitude().atan2(self.dot(other))
+    }
+
+    /// Returns the component of this vector along `direction`.
+    pub fn project_onto(self, direction: Vector3) -> Vector3 {
+        let length_squared = direction.magnitude_squared();
+        if length_squared == 0.0 {
+            Vector3::ZERO
+        } else {
+            direction * (self.dot(direction) / length_squared)
+        }
+    }
+
+    /// Linearly interpolates between the two vectors, returning `self` when `t` is 0 and
+    /// `other` when `t` is 1.
+    pub fn lerp(self, other: Vector3, t: f64) -> Vector3 {
+        self + (other - self) * t
+    }
+}
+
+impl From<(f64, f64, f64)> for Vector3 {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+impl From<Vector3> for (f64, f64, f64) {
+    fn from(v: Vector3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+impl From<Vector3> for [f64; 3] {
+    fn from(v: Vector3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, rhs: Vector3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: f64) -> Vector3 {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Vector3> for f64 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        rhs * self
+    }
+}
+
+impl MulAssign<f64> for Vector3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, rhs: f64) -> Vector3 {
+        Vector3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl DivAssign<f64> for Vector3 {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for Vector3 {
+    fn sum<I: Iterator<Item = Vector3>>(iter: I) -> Vector3 {
+        iter.fold(Vector3::ZERO, Add::add)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector3> for nalgebra::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        nalgebra::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for Vector3 {
+    fn from(v: nalgebra::Vector3<f64>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector3> for glam::DVec3 {
+    fn from(v: Vector3) -> Self {
+        glam::DVec3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec3> for Vector3 {
+    fn from(v: glam::DVec3) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::remote_type;
+
+remote_type!(
+    /// The ascent autopilot, which flies the vessel from the launch pad to a circular orbit.
+    object MechJeb.AscentAutopilot {
+        properties: {
+            {
+                Enabled {
+                    /// Returns whether the ascent autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_enabled -> bool,
+                    /// Sets whether the ascent autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_enabled(bool)
+                }
+            }
+            {
+                Status {
+                    /// Returns the status line of the ascent autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: status -> String
+                }
+            }
+            {
+                AscentPathIndex {
+                    /// Returns the ascent path used by the autopilot: 0 for the classic path, 1 for the stock-style gravity turn and 2 for primer vector guidance.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: ascent_path_index -> i32,
+                    /// Sets the ascent path used by the autopilot: 0 for the classic path, 1 for the stock-style gravity turn and 2 for primer vector guidance.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_ascent_path_index(i32)
+                }
+            }
+            {
+                DesiredInclination {
+                    /// Returns the inclination of the target orbit, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: desired_inclination -> f64,
+                    /// Sets the inclination of the target orbit, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_desired_inclination(f64)
+                }
+            }
+            {
+                DesiredOrbitAltitude {
+                    /// Returns the altitude of the target orbit, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: desired_orbit_altitude -> f64,
+                    /// Sets the altitude of the target orbit, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_desired_orbit_altitude(f64)
+                }
+            }
+            {
+                Autostage {
+                    /// Returns whether the autopilot stages the vessel automatically.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_autostage -> bool,
+                    /// Sets whether the autopilot stages the vessel automatically.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_autostage(bool)
+                }
+            }
+            {
+                SkipCircularization {
+                    /// Returns whether the autopilot stops after the apoapsis burn instead of circularizing.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_skip_circularization -> bool,
+                    /// Sets whether the autopilot stops after the apoapsis burn instead of circularizing.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_skip_circularization(bool)
+                }
+            }
+            {
+                CorrectiveSteering {
+                    /// Returns whether the autopilot steers to correct deviations from the ascent path.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_corrective_steering -> bool,
+                    /// Sets whether the autopilot steers to correct deviations from the ascent path.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_corrective_steering(bool)
+                }
+            }
+            {
+                ForceRoll {
+                    /// Returns whether the autopilot controls the roll of the vessel.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_force_roll -> bool,
+                    /// Sets whether the autopilot controls the roll of the vessel.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_force_roll(bool)
+                }
+            }
+            {
+                VerticalRoll {
+                    /// Returns the roll angle held during the vertical climb, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: vertical_roll -> f64,
+                    /// Sets the roll angle held during the vertical climb, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_vertical_roll(f64)
+                }
+            }
+            {
+                TurnRoll {
+                    /// Returns the roll angle held during the gravity turn, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: turn_roll -> f64,
+                    /// Sets the roll angle held during the gravity turn, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_turn_roll(f64)
+                }
+            }
+            {
+                LimitAoA {
+                    /// Returns whether the autopilot limits the angle of attack.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_limit_aoa -> bool,
+                    /// Sets whether the autopilot limits the angle of attack.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_limit_aoa(bool)
+                }
+            }
+            {
+                MaxAoA {
+                    /// Returns the maximum angle of attack, in degrees, when it is limited.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: max_aoa -> f64,
+                    /// Sets the maximum angle of attack, in degrees, when it is limited.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_max_aoa(f64)
+                }
+            }
+            {
+                LaunchPhaseAngle {
+                    /// Returns the phase angle to the target at which to launch to a rendezvous, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: launch_phase_angle -> f64,
+                    /// Sets the phase angle to the target at which to launch to a rendezvous, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_launch_phase_angle(f64)
+                }
+            }
+            {
+                LaunchLANDifference {
+                    /// Returns the difference in longitude of the ascending node at which to launch into the target plane, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: launch_lan_difference -> f64,
+                    /// Sets the difference in longitude of the ascending node at which to launch into the target plane, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_launch_lan_difference(f64)
+                }
+            }
+            {
+                WarpCountDown {
+                    /// Returns the number of seconds before launch at which time warp stops.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: warp_count_down -> i32,
+                    /// Sets the number of seconds before launch at which time warp stops.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_warp_count_down(i32)
+                }
+            }
+        }
+        methods: {
+            {
+                /// Launches at the right moment to rendezvous with the current target.
+                ///
+                /// **Game Scenes**: Flight
+                fn launch_to_rendezvous() {
+                    LaunchToRendezvous()
+                }
+            }
+            {
+                /// Launches at the right moment to reach the orbital plane of the current target.
+                ///
+                /// **Game Scenes**: Flight
+                fn launch_to_target_plane() {
+                    LaunchToTargetPlane()
+                }
+            }
+        }
+    }
+);
@@ -0,0 +1,127 @@
+use crate::remote_type;
+
+remote_type!(
+    /// The landing autopilot, which lands the vessel at a target or wherever it is heading.
+    object MechJeb.LandingAutopilot {
+        properties: {
+            {
+                Enabled {
+                    /// Returns whether the landing autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_enabled -> bool,
+                    /// Sets whether the landing autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_enabled(bool)
+                }
+            }
+            {
+                Status {
+                    /// Returns the status line of the landing autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: status -> String
+                }
+            }
+            {
+                TouchdownSpeed {
+                    /// Returns the vertical speed at touchdown, in meters per second.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: touchdown_speed -> f64,
+                    /// Sets the vertical speed at touchdown, in meters per second.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_touchdown_speed(f64)
+                }
+            }
+            {
+                DeployGears {
+                    /// Returns whether the landing gear is deployed before touchdown.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_deploy_gears -> bool,
+                    /// Sets whether the landing gear is deployed before touchdown.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_deploy_gears(bool)
+                }
+            }
+            {
+                DeployChutes {
+                    /// Returns whether parachutes are deployed during the descent.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_deploy_chutes -> bool,
+                    /// Sets whether parachutes are deployed during the descent.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_deploy_chutes(bool)
+                }
+            }
+            {
+                LimitGearsStage {
+                    /// Returns the last stage in which the landing gear is deployed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: limit_gears_stage -> i32,
+                    /// Sets the last stage in which the landing gear is deployed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_limit_gears_stage(i32)
+                }
+            }
+            {
+                LimitChutesStage {
+                    /// Returns the last stage in which parachutes are deployed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: limit_chutes_stage -> i32,
+                    /// Sets the last stage in which parachutes are deployed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_limit_chutes_stage(i32)
+                }
+            }
+            {
+                RcsAdjustment {
+                    /// Returns whether RCS is used to correct the trajectory during the final descent.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_rcs_adjustment -> bool,
+                    /// Sets whether RCS is used to correct the trajectory during the final descent.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_rcs_adjustment(bool)
+                }
+            }
+        }
+        methods: {
+            {
+                /// Lands the vessel at the current position target.
+                ///
+                /// **Game Scenes**: Flight
+                fn land_at_position_target() {
+                    LandAtPositionTarget()
+                }
+            }
+            {
+                /// Lands the vessel wherever its trajectory takes it.
+                ///
+                /// **Game Scenes**: Flight
+                fn land_untargeted() {
+                    LandUntargeted()
+                }
+            }
+            {
+                /// Stops the landing autopilot.
+                ///
+                /// **Game Scenes**: Flight
+                fn stop_landing() {
+                    StopLanding()
+                }
+            }
+        }
+    }
+);
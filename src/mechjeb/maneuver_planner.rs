@@ -0,0 +1,834 @@
+use crate::remote_type;
+use crate::spacecenter::Node;
+
+remote_type!(
+    /// The maneuver planner, which creates maneuver nodes for common orbital operations.
+    object MechJeb.ManeuverPlanner {
+        properties: {
+            {
+                OperationApoapsis {
+                    /// Returns the operation that changes the apoapsis.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_apoapsis -> OperationApoapsis
+                }
+            }
+            {
+                OperationCircularize {
+                    /// Returns the operation that circularizes the orbit.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_circularize -> OperationCircularize
+                }
+            }
+            {
+                OperationCourseCorrection {
+                    /// Returns the operation that fine-tunes the closest approach to the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_course_correction -> OperationCourseCorrection
+                }
+            }
+            {
+                OperationEllipticize {
+                    /// Returns the operation that changes both the apoapsis and the periapsis.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_ellipticize -> OperationEllipticize
+                }
+            }
+            {
+                OperationInclination {
+                    /// Returns the operation that changes the inclination.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_inclination -> OperationInclination
+                }
+            }
+            {
+                OperationInterplanetaryTransfer {
+                    /// Returns the operation that transfers to another planet.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_interplanetary_transfer -> OperationInterplanetaryTransfer
+                }
+            }
+            {
+                OperationKillRelVel {
+                    /// Returns the operation that matches velocities with the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_kill_rel_vel -> OperationKillRelVel
+                }
+            }
+            {
+                OperationLambert {
+                    /// Returns the operation that intercepts the target after a given interval.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_lambert -> OperationLambert
+                }
+            }
+            {
+                OperationLan {
+                    /// Returns the operation that changes the longitude of the ascending node.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_lan -> OperationLan
+                }
+            }
+            {
+                OperationMoonReturn {
+                    /// Returns the operation that returns from a moon to the body it orbits.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_moon_return -> OperationMoonReturn
+                }
+            }
+            {
+                OperationPeriapsis {
+                    /// Returns the operation that changes the periapsis.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_periapsis -> OperationPeriapsis
+                }
+            }
+            {
+                OperationPlane {
+                    /// Returns the operation that matches the orbital plane of the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_plane -> OperationPlane
+                }
+            }
+            {
+                OperationSemiMajor {
+                    /// Returns the operation that changes the semi-major axis.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_semi_major -> OperationSemiMajor
+                }
+            }
+            {
+                OperationTransfer {
+                    /// Returns the operation that performs a Hohmann transfer to the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: operation_transfer -> OperationTransfer
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes the apoapsis.
+    object MechJeb.OperationApoapsis {
+        properties: {
+            {
+                NewApoapsis {
+                    /// Returns the new apoapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_apoapsis -> f64,
+                    /// Sets the new apoapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_apoapsis(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Circularizes the orbit.
+    object MechJeb.OperationCircularize {
+        properties: {
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Fine-tunes the closest approach to the target.
+    object MechJeb.OperationCourseCorrection {
+        properties: {
+            {
+                CourseCorrectFinalPeA {
+                    /// Returns the periapsis altitude to aim for at the target body, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: course_correct_final_pe_a -> f64,
+                    /// Sets the periapsis altitude to aim for at the target body, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_course_correct_final_pe_a(f64)
+                }
+            }
+            {
+                InterceptDistance {
+                    /// Returns the closest approach distance to aim for at the target vessel, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: intercept_distance -> f64,
+                    /// Sets the closest approach distance to aim for at the target vessel, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_intercept_distance(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes both the apoapsis and the periapsis.
+    object MechJeb.OperationEllipticize {
+        properties: {
+            {
+                NewApoapsis {
+                    /// Returns the new apoapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_apoapsis -> f64,
+                    /// Sets the new apoapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_apoapsis(f64)
+                }
+            }
+            {
+                NewPeriapsis {
+                    /// Returns the new periapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_periapsis -> f64,
+                    /// Sets the new periapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_periapsis(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes the inclination.
+    object MechJeb.OperationInclination {
+        properties: {
+            {
+                NewInclination {
+                    /// Returns the new inclination, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_inclination -> f64,
+                    /// Sets the new inclination, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_inclination(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Transfers to another planet.
+    object MechJeb.OperationInterplanetaryTransfer {
+        properties: {
+            {
+                WaitForPhaseAngle {
+                    /// Returns whether the transfer waits for the optimal phase angle.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_wait_for_phase_angle -> bool,
+                    /// Sets whether the transfer waits for the optimal phase angle.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_wait_for_phase_angle(bool)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Matches velocities with the target.
+    object MechJeb.OperationKillRelVel {
+        properties: {
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Intercepts the target after a given interval.
+    object MechJeb.OperationLambert {
+        properties: {
+            {
+                InterceptInterval {
+                    /// Returns the time after the burn at which to intercept the target, in seconds.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: intercept_interval -> f64,
+                    /// Sets the time after the burn at which to intercept the target, in seconds.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_intercept_interval(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes the longitude of the ascending node.
+    object MechJeb.OperationLan {
+        properties: {
+            {
+                NewLAN {
+                    /// Returns the new longitude of the ascending node, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_lan -> f64,
+                    /// Sets the new longitude of the ascending node, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_lan(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Returns from a moon to the body it orbits.
+    object MechJeb.OperationMoonReturn {
+        properties: {
+            {
+                MoonReturnAltitude {
+                    /// Returns the periapsis altitude to aim for at the parent body, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: moon_return_altitude -> f64,
+                    /// Sets the periapsis altitude to aim for at the parent body, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_moon_return_altitude(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes the periapsis.
+    object MechJeb.OperationPeriapsis {
+        properties: {
+            {
+                NewPeriapsis {
+                    /// Returns the new periapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_periapsis -> f64,
+                    /// Sets the new periapsis altitude, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_periapsis(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Matches the orbital plane of the target.
+    object MechJeb.OperationPlane {
+        properties: {
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Changes the semi-major axis.
+    object MechJeb.OperationSemiMajor {
+        properties: {
+            {
+                NewSemiMajorAxis {
+                    /// Returns the new semi-major axis, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: new_semi_major_axis -> f64,
+                    /// Sets the new semi-major axis, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_new_semi_major_axis(f64)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Performs a Hohmann transfer to the target.
+    object MechJeb.OperationTransfer {
+        properties: {
+            {
+                InterceptOnly {
+                    /// Returns whether the transfer only intercepts the target, without a capture burn.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_intercept_only -> bool,
+                    /// Sets whether the transfer only intercepts the target, without a capture burn.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_intercept_only(bool)
+                }
+            }
+            {
+                SimpleTransfer {
+                    /// Returns whether a simple coplanar transfer is used.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_simple_transfer -> bool,
+                    /// Sets whether a simple coplanar transfer is used.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_simple_transfer(bool)
+                }
+            }
+            {
+                ErrorMessage {
+                    /// Returns the error from the last call to `make_nodes`, or an empty string if it succeeded.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: error_message -> String
+                }
+            }
+            {
+                TimeSelector {
+                    /// Returns when the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_selector -> TimeSelector
+                }
+            }
+        }
+        methods: {
+            {
+                /// Creates the maneuver nodes for the operation. If the operation fails, the error is
+                /// returned by `error_message`.
+                ///
+                /// **Game Scenes**: Flight
+                fn make_nodes() -> Vec<Node> {
+                    MakeNodes()
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// Chooses when a maneuver planner operation is performed.
+    object MechJeb.TimeSelector {
+        properties: {
+            {
+                TimeReference {
+                    /// Returns the point in the orbit at which the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: time_reference -> TimeReference,
+                    /// Sets the point in the orbit at which the maneuver is performed.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_time_reference(TimeReference)
+                }
+            }
+            {
+                LeadTime {
+                    /// Returns the time after the reference point, in seconds, for `TimeReference::XFromNow`.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: lead_time -> f64,
+                    /// Sets the time after the reference point, in seconds, for `TimeReference::XFromNow`.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_lead_time(f64)
+                }
+            }
+            {
+                CircularizeAltitude {
+                    /// Returns the altitude, in meters, for `TimeReference::Altitude`.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: circularize_altitude -> f64,
+                    /// Sets the altitude, in meters, for `TimeReference::Altitude`.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_circularize_altitude(f64)
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// The point in the orbit at which a maneuver is performed.
+    enum TimeReference {
+        /// Computed by the operation.
+        Computed = 0,
+        /// A fixed time from now.
+        XFromNow = 1,
+        /// At the apoapsis.
+        Apoapsis = 2,
+        /// At the periapsis.
+        Periapsis = 3,
+        /// At a given altitude.
+        Altitude = 4,
+        /// At the equatorial ascending node.
+        EqAscending = 5,
+        /// At the equatorial descending node.
+        EqDescending = 6,
+        /// At the ascending node relative to the target.
+        RelAscending = 7,
+        /// At the descending node relative to the target.
+        RelDescending = 8,
+        /// At the closest approach to the target.
+        ClosestApproach = 9,
+        /// At the highest equatorial node.
+        EqHighestAd = 10,
+        /// At the nearest equatorial node.
+        EqNearestAd = 11,
+        /// At the highest node relative to the target.
+        RelHighestAd = 12,
+        /// At the nearest node relative to the target.
+        RelNearestAd = 13,
+    }
+);
@@ -0,0 +1,69 @@
+use crate::remote_type;
+
+mod ascent_autopilot;
+mod landing_autopilot;
+mod maneuver_planner;
+mod rendezvous_autopilot;
+mod smart_ass;
+
+pub use self::ascent_autopilot::*;
+pub use self::landing_autopilot::*;
+pub use self::maneuver_planner::*;
+pub use self::rendezvous_autopilot::*;
+pub use self::smart_ass::*;
+
+remote_type!(
+    /// This service provides functionality to interact with MechJeb 2.
+    service MechJeb {
+        properties: {
+            {
+                APIReady {
+                    /// Returns whether MechJeb is installed and its API is ready to be used.
+                    ///
+                    /// **Game Scenes**: All
+                    get: is_available -> bool
+                }
+            }
+            {
+                AscentAutopilot {
+                    /// Returns the ascent autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: ascent_autopilot -> AscentAutopilot
+                }
+            }
+            {
+                LandingAutopilot {
+                    /// Returns the landing autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: landing_autopilot -> LandingAutopilot
+                }
+            }
+            {
+                ManeuverPlanner {
+                    /// Returns the maneuver planner.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: maneuver_planner -> ManeuverPlanner
+                }
+            }
+            {
+                RendezvousAutopilot {
+                    /// Returns the rendezvous autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: rendezvous_autopilot -> RendezvousAutopilot
+                }
+            }
+            {
+                SmartASS {
+                    /// Returns Smart A.S.S.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: smart_ass -> SmartASS
+                }
+            }
+        }
+    }
+);
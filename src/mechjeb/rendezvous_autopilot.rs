@@ -0,0 +1,53 @@
+use crate::remote_type;
+
+remote_type!(
+    /// The rendezvous autopilot, which brings the vessel close to its target.
+    object MechJeb.RendezvousAutopilot {
+        properties: {
+            {
+                Enabled {
+                    /// Returns whether the rendezvous autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_enabled -> bool,
+                    /// Sets whether the rendezvous autopilot is enabled.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_enabled(bool)
+                }
+            }
+            {
+                Status {
+                    /// Returns the status line of the rendezvous autopilot.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: status -> String
+                }
+            }
+            {
+                DesiredDistance {
+                    /// Returns the distance from the target at which the rendezvous finishes, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: desired_distance -> f64,
+                    /// Sets the distance from the target at which the rendezvous finishes, in meters.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_desired_distance(f64)
+                }
+            }
+            {
+                MaxPhasingOrbits {
+                    /// Returns the maximum number of orbits to spend phasing with the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: max_phasing_orbits -> f64,
+                    /// Sets the maximum number of orbits to spend phasing with the target.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_max_phasing_orbits(f64)
+                }
+            }
+        }
+    }
+);
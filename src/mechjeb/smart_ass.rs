@@ -0,0 +1,186 @@
+use crate::remote_type;
+
+remote_type!(
+    /// Smart A.S.S., which holds the vessel in a chosen orientation.
+    object MechJeb.SmartASS {
+        properties: {
+            {
+                AutopilotMode {
+                    /// Returns the orientation held by Smart A.S.S..
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: autopilot_mode -> SmartASSAutopilotMode,
+                    /// Sets the orientation held by Smart A.S.S..
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_autopilot_mode(SmartASSAutopilotMode)
+                }
+            }
+            {
+                InterfaceMode {
+                    /// Returns the group of modes shown in the Smart A.S.S. window.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: interface_mode -> SmartASSInterfaceMode,
+                    /// Sets the group of modes shown in the Smart A.S.S. window.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_interface_mode(SmartASSInterfaceMode)
+                }
+            }
+            {
+                ForcePitch {
+                    /// Returns whether the pitch is held in the surface mode.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_force_pitch -> bool,
+                    /// Sets whether the pitch is held in the surface mode.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_force_pitch(bool)
+                }
+            }
+            {
+                ForceRoll {
+                    /// Returns whether the roll is held.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_force_roll -> bool,
+                    /// Sets whether the roll is held.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_force_roll(bool)
+                }
+            }
+            {
+                ForceYaw {
+                    /// Returns whether the yaw is held in the surface mode.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: is_force_yaw -> bool,
+                    /// Sets whether the yaw is held in the surface mode.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_force_yaw(bool)
+                }
+            }
+            {
+                SurfaceHeading {
+                    /// Returns the heading held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: surface_heading -> f64,
+                    /// Sets the heading held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_surface_heading(f64)
+                }
+            }
+            {
+                SurfacePitch {
+                    /// Returns the pitch held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: surface_pitch -> f64,
+                    /// Sets the pitch held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_surface_pitch(f64)
+                }
+            }
+            {
+                SurfaceRoll {
+                    /// Returns the roll held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    get: surface_roll -> f64,
+                    /// Sets the roll held in the surface mode, in degrees.
+                    ///
+                    /// **Game Scenes**: Flight
+                    set: set_surface_roll(f64)
+                }
+            }
+        }
+        methods: {
+            {
+                /// Applies changes to the mode and its settings.
+                ///
+                /// # Arguments
+                /// `reset_pid` - Whether to reset the attitude controller.
+                ///
+                /// **Game Scenes**: Flight
+                fn update(reset_pid: bool) {
+                    Update(reset_pid)
+                }
+            }
+        }
+    }
+);
+
+remote_type!(
+    /// The orientation held by Smart A.S.S.
+    enum SmartASSAutopilotMode {
+        /// Smart A.S.S. is off.
+        Off = 0,
+        /// Kill rotation.
+        KillRot = 1,
+        /// Point at the next maneuver node.
+        Node = 2,
+        /// Hold the surface heading, pitch and roll.
+        Surface = 3,
+        /// Orbital prograde.
+        Prograde = 4,
+        /// Orbital retrograde.
+        Retrograde = 5,
+        /// Orbit normal.
+        NormalPlus = 6,
+        /// Orbit anti-normal.
+        NormalMinus = 7,
+        /// Radial out.
+        RadialPlus = 8,
+        /// Radial in.
+        RadialMinus = 9,
+        /// Prograde relative to the target.
+        RelativePlus = 10,
+        /// Retrograde relative to the target.
+        RelativeMinus = 11,
+        /// Toward the target.
+        TargetPlus = 12,
+        /// Away from the target.
+        TargetMinus = 13,
+        /// Parallel to the target's orientation.
+        ParallelPlus = 14,
+        /// Anti-parallel to the target's orientation.
+        ParallelMinus = 15,
+        /// A custom direction relative to a chosen reference.
+        Advanced = 16,
+        /// Controlled by another autopilot.
+        Automatic = 17,
+        /// Surface prograde.
+        SurfacePrograde = 18,
+        /// Surface retrograde.
+        SurfaceRetrograde = 19,
+        /// Horizontal prograde.
+        HorizontalPlus = 20,
+        /// Horizontal retrograde.
+        HorizontalMinus = 21,
+        /// Straight up.
+        VerticalPlus = 22,
+    }
+);
+
+remote_type!(
+    /// The group of modes shown in the Smart A.S.S. window.
+    enum SmartASSInterfaceMode {
+        /// Modes relative to the orbit.
+        Orbital = 0,
+        /// Modes relative to the surface.
+        Surface = 1,
+        /// Modes relative to the target.
+        Target = 2,
+        /// A custom direction.
+        Advanced = 3,
+        /// Controlled by another autopilot.
+        Automatic = 4,
+    }
+);
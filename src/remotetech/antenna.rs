@@ -1,6 +1,5 @@
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::{CelestialBody, Part, Vessel};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// A RemoteTech antenna.
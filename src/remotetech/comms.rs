@@ -1,7 +1,6 @@
 use super::Antenna;
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::Vessel;
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// Communications for a vessel.
@@ -0,0 +1,245 @@
+use super::{Node, Vessel};
+use crate::remote_type;
+
+remote_type!(
+/// Manages the alarms of the stock alarm clock. Obtained by calling
+/// `SpaceCenter::alarm_manager()`.
+object SpaceCenter.AlarmManager {
+    properties: {
+        {
+            Alarms {
+                /// Returns a list of all the alarms.
+                ///
+                /// **Game Scenes**: All
+                get: alarms -> Vec<Alarm>
+            }
+        }
+    }
+    static_methods: {
+        {
+            /// Creates an alarm that fires at the given time.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `time` – Universal time at which the alarm fires.
+            fn add_alarm(time: f64) -> Alarm<'a> {
+                AddAlarm(time)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_alarm()` whose optional arguments can be
+            /// set. The title defaults to "Alarm" and the description to an empty string.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `time` – Universal time at which the alarm fires.
+            fn add_alarm_with(time: f64) [title: &str, description: &str] -> Alarm<'a> {
+                AddAlarm(time)
+            }
+        }
+        {
+            /// Creates an alarm for a vessel that fires at the given time.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `time` – Universal time at which the alarm fires.
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_vessel_alarm(time: f64, vessel: &Vessel) -> Alarm<'a> {
+                AddVesselAlarm(time, vessel)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_vessel_alarm()` whose optional arguments
+            /// can be set. The title defaults to "Alarm" and the description to an empty string.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `time` – Universal time at which the alarm fires.
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_vessel_alarm_with(time: f64, vessel: &Vessel)
+                [title: &str, description: &str] -> Alarm<'a> {
+                AddVesselAlarm(time, vessel)
+            }
+        }
+        {
+            /// Creates an alarm that fires before a vessel reaches its apoapsis.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_apoapsis_alarm(vessel: &Vessel) -> Alarm<'a> {
+                AddApoapsisAlarm(vessel)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_apoapsis_alarm()` whose optional arguments
+            /// can be set. The title defaults to "Apoapsis Alarm" and the description to an empty
+            /// string. The offset defaults to 60 seconds.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_apoapsis_alarm_with(vessel: &Vessel)
+                [offset: f64, title: &str, description: &str] -> Alarm<'a> {
+                AddApoapsisAlarm(vessel)
+            }
+        }
+        {
+            /// Creates an alarm that fires before a vessel reaches its periapsis.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_periapsis_alarm(vessel: &Vessel) -> Alarm<'a> {
+                AddPeriapsisAlarm(vessel)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_periapsis_alarm()` whose optional arguments
+            /// can be set. The title defaults to "Periapsis Alarm" and the description to an empty
+            /// string. The offset defaults to 60 seconds.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_periapsis_alarm_with(vessel: &Vessel)
+                [offset: f64, title: &str, description: &str] -> Alarm<'a> {
+                AddPeriapsisAlarm(vessel)
+            }
+        }
+        {
+            /// Creates an alarm that fires before a maneuver node.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            /// * `node` – The maneuver node.
+            fn add_maneuver_node_alarm(vessel: &Vessel, node: &Node) -> Alarm<'a> {
+                AddManeuverNodeAlarm(vessel, node)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_maneuver_node_alarm()` whose optional
+            /// arguments can be set. The title defaults to "Maneuver Node Alarm" and the
+            /// description to an empty string. The offset defaults to 60 seconds.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            /// * `node` – The maneuver node.
+            fn add_maneuver_node_alarm_with(vessel: &Vessel, node: &Node)
+                [offset: f64, add_burn_time: bool, title: &str, description: &str] -> Alarm<'a> {
+                AddManeuverNodeAlarm(vessel, node)
+            }
+        }
+        {
+            /// Creates an alarm that fires before a vessel changes sphere of influence.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_soi_alarm(vessel: &Vessel) -> Alarm<'a> {
+                AddSOIAlarm(vessel)
+            }
+        }
+        {
+            /// Returns a builder for `AlarmManager::add_soi_alarm()` whose optional arguments can
+            /// be set. The title defaults to "SOI Change" and the description to an empty string.
+            /// The offset defaults to 60 seconds.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `vessel` – The vessel the alarm is attached to.
+            fn add_soi_alarm_with(vessel: &Vessel)
+                [offset: f64, title: &str, description: &str] -> Alarm<'a> {
+                AddSOIAlarm(vessel)
+            }
+        }
+    }
+}
+);
+
+remote_type!(
+/// An alarm of the stock alarm clock. Obtained by calling `AlarmManager::alarms()` or
+/// one of the `AlarmManager::add_*_alarm()` methods.
+object SpaceCenter.Alarm {
+    properties: {
+        {
+            ID {
+                /// Returns the unique identifier for the alarm.
+                ///
+                /// **Game Scenes**: All
+                get: alarm_id -> u32
+            }
+        }
+        {
+            Type {
+                /// Returns the type of the alarm.
+                ///
+                /// **Game Scenes**: All
+                get: alarm_type -> String
+            }
+        }
+        {
+            Title {
+                /// Returns the title of the alarm.
+                ///
+                /// **Game Scenes**: All
+                get: title -> String
+            }
+        }
+        {
+            Description {
+                /// Returns the description of the alarm.
+                ///
+                /// **Game Scenes**: All
+                get: description -> String
+            }
+        }
+        {
+            Time {
+                /// Returns the time at which the alarm will fire.
+                ///
+                /// **Game Scenes**: All
+                get: time -> f64
+            }
+        }
+        {
+            TimeTill {
+                /// Returns the number of seconds until the alarm will fire.
+                ///
+                /// **Game Scenes**: All
+                get: time_till -> f64
+            }
+        }
+        {
+            EventOffset {
+                /// Returns the number of seconds before the event that the alarm will fire.
+                ///
+                /// **Game Scenes**: All
+                get: event_offset -> f64
+            }
+        }
+        {
+            Vessel {
+                /// Returns the vessel that the alarm is attached to, if any.
+                ///
+                /// **Game Scenes**: All
+                get: vessel -> Option<Vessel>
+            }
+        }
+    }
+}
+);
@@ -1,6 +1,5 @@
 use super::{ReferenceFrame, SASMode};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject, Vector3};
+use crate::{remote_type, Vector3};
 
 remote_type!(
 /// Provides basic auto-piloting utilities for a vessel. Created by calling `Vessel::auto_pilot()`.
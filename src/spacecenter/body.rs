@@ -1,6 +1,5 @@
 use super::{Orbit, ReferenceFrame};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 use std::collections::HashSet;
 
@@ -1,6 +1,5 @@
 use super::{CelestialBody, Node, Vessel};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// Controls the game’s camera. Obtained by calling `SpaceCenter::camera()`.
@@ -1,6 +1,5 @@
 use super::Vessel;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// Used to interact with CommNet for a given vessel. Obtained by calling `Vessel::comms()`.
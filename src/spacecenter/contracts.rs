@@ -1,5 +1,4 @@
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 use std::collections::HashSet;
 
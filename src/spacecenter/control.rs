@@ -1,6 +1,5 @@
 use super::{Node, Vessel};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// Used to manipulate the controls of a vessel. This includes adjusting the throttle,
@@ -492,6 +491,18 @@ object SpaceCenter.Control {
                 AddNode(ut, prograde, normal, radial)
             }
         }
+        {
+            /// Returns a builder for `Control::add_node()` whose delta-v components are
+            /// optional. Any component that is not set defaults to zero on the server.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `ut` – Universal time of the maneuver node.
+            fn add_node_with(ut: f64) [prograde: f32, normal: f32, radial: f32] -> Node<'a> {
+                AddNode(ut)
+            }
+        }
         {
             /// Remove all maneuver nodes.
             ///
@@ -1,6 +1,5 @@
 use super::CelestialBody;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// Used to get flight telemetry for a vessel, by calling `Vessel::flight()`. All of
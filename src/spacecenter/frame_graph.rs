@@ -0,0 +1,351 @@
+use super::{ReferenceFrame, SpaceCenter, Vessel};
+use crate::client::{
+    decode_space_center_result, Connection, KrpcResult, ProcedureCall, ResponseError, Stream,
+};
+use crate::{Quaternion, Vector3};
+
+use std::collections::HashMap;
+
+/// The unit vectors along the axes of a reference frame, used to measure its angular velocity.
+const AXES: [Vector3; 3] = [
+    Vector3::new(1.0, 0.0, 0.0),
+    Vector3::new(0.0, 1.0, 0.0),
+    Vector3::new(0.0, 0.0, 1.0),
+];
+
+/// The placement and motion of one reference frame, the source, relative to another, the
+/// target, at one instant.
+///
+/// Once fetched, it converts positions, directions, rotations and velocities from the source
+/// frame to the target frame locally, with the same results as
+/// `SpaceCenter::transform_position`, `transform_direction`, `transform_rotation` and
+/// `transform_velocity`, but without a remote procedure call per vector. The results are only
+/// exact for the instant the transform was fetched at, so it needs to be fetched again
+/// whenever the game time advances.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrameTransform {
+    /// The position of the origin of the source frame, in the target frame.
+    pub position: Vector3,
+    /// The rotation of the axes of the source frame, in the target frame.
+    pub rotation: Quaternion,
+    /// The velocity of the origin of the source frame, in the target frame.
+    pub velocity: Vector3,
+    /// The angular velocity of the source frame, in the target frame.
+    pub angular_velocity: Vector3,
+}
+
+impl FrameTransform {
+    /// The transform from a frame to itself.
+    pub const IDENTITY: FrameTransform = FrameTransform {
+        position: Vector3::ZERO,
+        rotation: Quaternion::IDENTITY,
+        velocity: Vector3::ZERO,
+        angular_velocity: Vector3::ZERO,
+    };
+
+    /// Fetches the transform from `from` to `to` in a single request.
+    pub fn fetch(
+        connection: &Connection,
+        from: &ReferenceFrame,
+        to: &ReferenceFrame,
+    ) -> KrpcResult<FrameTransform> {
+        let results = connection.invoke_batch(Self::calls(connection, from, to)?)?;
+        Self::decode(connection, &mut results.into_iter())
+    }
+
+    /// Builds a transform from the answers of the server for the source frame:
+    ///
+    /// * `position` - `transform_position` of the zero vector.
+    /// * `rotation` - `transform_rotation` of the identity quaternion.
+    /// * `velocity` - `transform_velocity` of the zero vector at the zero vector.
+    /// * `axis_velocities` - `transform_velocity` of the zero vector at each of the unit vectors
+    ///   along the x, y and z axes, in that order.
+    ///
+    /// This is what `fetch` does, and can be used to build a transform from values obtained in
+    /// other ways, such as a recorded session.
+    pub fn from_samples(
+        position: Vector3,
+        rotation: Quaternion,
+        velocity: Vector3,
+        axis_velocities: [Vector3; 3],
+    ) -> FrameTransform {
+        // A point fixed in a rotating frame moves at ω × r relative to its origin. Summing
+        // r × (ω × r) = ω - r (r · ω) over the three axes leaves 2ω.
+        let angular_velocity = AXES
+            .iter()
+            .zip(axis_velocities.iter())
+            .map(|(&axis, &axis_velocity)| rotation.rotate(axis).cross(axis_velocity - velocity))
+            .sum::<Vector3>()
+            / 2.0;
+
+        FrameTransform {
+            position,
+            rotation,
+            velocity,
+            angular_velocity,
+        }
+    }
+
+    /// Converts a position in the source frame to the target frame.
+    pub fn transform_position(&self, position: Vector3) -> Vector3 {
+        self.position + self.rotation.rotate(position)
+    }
+
+    /// Converts a direction in the source frame to the target frame.
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.rotation.rotate(direction)
+    }
+
+    /// Converts a rotation in the source frame to the target frame.
+    pub fn transform_rotation(&self, rotation: Quaternion) -> Quaternion {
+        self.rotation * rotation
+    }
+
+    /// Converts a velocity, acting at `position`, in the source frame to the target frame.
+    pub fn transform_velocity(&self, position: Vector3, velocity: Vector3) -> Vector3 {
+        self.velocity
+            + self.rotation.rotate(velocity)
+            + self.angular_velocity.cross(self.rotation.rotate(position))
+    }
+
+    /// Returns the transform from the target frame back to the source frame.
+    pub fn inverse(&self) -> FrameTransform {
+        let rotation = self.rotation.inverse();
+        FrameTransform {
+            position: -rotation.rotate(self.position),
+            rotation,
+            velocity: rotation.rotate(self.angular_velocity.cross(self.position) - self.velocity),
+            angular_velocity: -rotation.rotate(self.angular_velocity),
+        }
+    }
+
+    /// Returns the transform that applies this transform and then `next`, whose source frame
+    /// must be the target frame of this one.
+    pub fn then(&self, next: &FrameTransform) -> FrameTransform {
+        FrameTransform {
+            position: next.transform_position(self.position),
+            rotation: next.rotation * self.rotation,
+            velocity: next.transform_velocity(self.position, self.velocity),
+            angular_velocity: next.angular_velocity + next.rotation.rotate(self.angular_velocity),
+        }
+    }
+
+    /// The calls whose results are decoded by `decode`, in order.
+    fn calls(
+        connection: &Connection,
+        from: &ReferenceFrame,
+        to: &ReferenceFrame,
+    ) -> KrpcResult<Vec<ProcedureCall>> {
+        let calls = SpaceCenter::new(connection).call();
+        let mut batch = vec![
+            calls.transform_position(Vector3::ZERO, from, to)?,
+            calls.transform_rotation(Quaternion::IDENTITY, from, to)?,
+            calls.transform_velocity(Vector3::ZERO, Vector3::ZERO, from, to)?,
+        ];
+        for &axis in AXES.iter() {
+            batch.push(calls.transform_velocity(axis, Vector3::ZERO, from, to)?);
+        }
+        Ok(batch)
+    }
+
+    /// Decodes the results of the calls made by `calls`, taking them from `results`.
+    fn decode<I>(connection: &Connection, results: &mut I) -> KrpcResult<FrameTransform>
+    where
+        I: Iterator<Item = Result<Vec<u8>, ResponseError>>,
+    {
+        let position = decode_space_center_result(results, connection, "TransformPosition")?;
+        let rotation = decode_space_center_result(results, connection, "TransformRotation")?;
+        let velocity = decode_space_center_result(results, connection, "TransformVelocity")?;
+        let mut axis_velocities = [Vector3::ZERO; 3];
+        for axis_velocity in axis_velocities.iter_mut() {
+            *axis_velocity = decode_space_center_result(results, connection, "TransformVelocity")?;
+        }
+
+        Ok(FrameTransform::from_samples(
+            position,
+            rotation,
+            velocity,
+            axis_velocities,
+        ))
+    }
+}
+
+/// A set of reference frames whose transforms to a common root frame are fetched together, so
+/// that vectors can be converted between any two of them locally.
+///
+/// ```ignore
+/// let mut graph = FrameGraph::new(&client, body.non_rotating_reference_frame()?);
+/// graph.add_frame(vessel.reference_frame()?);
+/// graph.add_frame(target.reference_frame()?);
+///
+/// loop {
+///     graph.update()?;
+///     let transform = graph.transform(&vessel_frame, &target_frame).unwrap();
+///     for point in points.iter() {
+///         let position = transform.transform_position(*point);
+///         // ...
+///     }
+/// }
+/// ```
+pub struct FrameGraph<'a> {
+    connection: &'a Connection,
+    root: ReferenceFrame<'a>,
+    transforms: HashMap<ReferenceFrame<'a>, Option<FrameTransform>>,
+}
+
+impl<'a> FrameGraph<'a> {
+    /// Creates a graph whose transforms are fetched relative to `root`.
+    pub fn new(connection: &'a Connection, root: ReferenceFrame<'a>) -> Self {
+        FrameGraph {
+            connection,
+            root,
+            transforms: HashMap::new(),
+        }
+    }
+
+    /// Returns the frame that all of the transforms are fetched relative to.
+    pub fn root(&self) -> &ReferenceFrame<'a> {
+        &self.root
+    }
+
+    /// Adds a frame to the graph. Its transform is available after the next `update`.
+    pub fn add_frame(&mut self, frame: ReferenceFrame<'a>) {
+        if frame != self.root {
+            self.transforms.entry(frame).or_insert(None);
+        }
+    }
+
+    /// Removes a frame from the graph.
+    pub fn remove_frame(&mut self, frame: &ReferenceFrame<'a>) {
+        self.transforms.remove(frame);
+    }
+
+    /// Sets the transform from `frame` to the root frame, adding the frame if needed. Use this
+    /// to feed the graph from a `FrameStream` instead of `update`.
+    pub fn set_transform(&mut self, frame: ReferenceFrame<'a>, transform: FrameTransform) {
+        self.transforms.insert(frame, Some(transform));
+    }
+
+    /// Fetches the transforms of all of the frames in the graph in a single request.
+    pub fn update(&mut self) -> KrpcResult<()> {
+        let frames: Vec<_> = self.transforms.keys().cloned().collect();
+        let mut calls = Vec::with_capacity(frames.len() * (AXES.len() + 3));
+        for frame in frames.iter() {
+            calls.extend(FrameTransform::calls(self.connection, frame, &self.root)?);
+        }
+
+        let mut results = self.connection.invoke_batch(calls)?.into_iter();
+        for frame in frames {
+            let transform = FrameTransform::decode(self.connection, &mut results)?;
+            self.transforms.insert(frame, Some(transform));
+        }
+        Ok(())
+    }
+
+    /// Returns the transform from `from` to `to`, or `None` if either frame has not been added
+    /// to the graph or has not been updated since.
+    pub fn transform(
+        &self,
+        from: &ReferenceFrame<'a>,
+        to: &ReferenceFrame<'a>,
+    ) -> Option<FrameTransform> {
+        if from == to {
+            return Some(FrameTransform::IDENTITY);
+        }
+        Some(self.to_root(from)?.then(&self.to_root(to)?.inverse()))
+    }
+
+    fn to_root(&self, frame: &ReferenceFrame<'a>) -> Option<FrameTransform> {
+        if *frame == self.root {
+            Some(FrameTransform::IDENTITY)
+        } else {
+            self.transforms.get(frame).cloned().flatten()
+        }
+    }
+}
+
+/// Streams the transform from one reference frame to another, so that it can be read each tick
+/// without a request.
+pub struct FrameStream<'a> {
+    position: Stream<'a, Vector3>,
+    rotation: Stream<'a, Quaternion>,
+    velocity: Stream<'a, Vector3>,
+    axis_velocities: [Stream<'a, Vector3>; 3],
+}
+
+impl<'a> FrameStream<'a> {
+    /// Streams the transform from `from` to `to`, using the `SpaceCenter` transform procedures.
+    pub fn new(
+        connection: &'a Connection,
+        from: &ReferenceFrame,
+        to: &ReferenceFrame,
+    ) -> KrpcResult<Self> {
+        let calls = SpaceCenter::new(connection).call();
+        Ok(FrameStream {
+            position: connection.add_stream_for_call(calls.transform_position(
+                Vector3::ZERO,
+                from,
+                to,
+            )?)?,
+            rotation: connection.add_stream_for_call(calls.transform_rotation(
+                Quaternion::IDENTITY,
+                from,
+                to,
+            )?)?,
+            velocity: connection.add_stream_for_call(calls.transform_velocity(
+                Vector3::ZERO,
+                Vector3::ZERO,
+                from,
+                to,
+            )?)?,
+            axis_velocities: Self::axis_velocities(connection, from, to)?,
+        })
+    }
+
+    /// Streams the transform from the reference frame of `vessel` to `to`, using
+    /// `Vessel::position`, `rotation` and `velocity`.
+    pub fn vessel(
+        connection: &'a Connection,
+        vessel: &Vessel,
+        to: &ReferenceFrame,
+    ) -> KrpcResult<Self> {
+        let calls = vessel.call();
+        Ok(FrameStream {
+            position: connection.add_stream_for_call(calls.position(to)?)?,
+            rotation: connection.add_stream_for_call(calls.rotation(to)?)?,
+            velocity: connection.add_stream_for_call(calls.velocity(to)?)?,
+            axis_velocities: Self::axis_velocities(connection, &vessel.reference_frame()?, to)?,
+        })
+    }
+
+    fn axis_velocities(
+        connection: &'a Connection,
+        from: &ReferenceFrame,
+        to: &ReferenceFrame,
+    ) -> KrpcResult<[Stream<'a, Vector3>; 3]> {
+        let calls = SpaceCenter::new(connection).call();
+        let stream = |axis| -> KrpcResult<Stream<'a, Vector3>> {
+            connection.add_stream_for_call(calls.transform_velocity(
+                axis,
+                Vector3::ZERO,
+                from,
+                to,
+            )?)
+        };
+        Ok([stream(AXES[0])?, stream(AXES[1])?, stream(AXES[2])?])
+    }
+
+    /// Returns the most recent transform received from the server.
+    pub fn value(&self) -> KrpcResult<FrameTransform> {
+        Ok(FrameTransform::from_samples(
+            self.position.value()?,
+            self.rotation.value()?,
+            self.velocity.value()?,
+            [
+                self.axis_velocities[0].value()?,
+                self.axis_velocities[1].value()?,
+                self.axis_velocities[2].value()?,
+            ],
+        ))
+    }
+}
@@ -0,0 +1,64 @@
+use super::{CelestialBody, SpaceCenter};
+use crate::client::KrpcResult;
+use crate::remote_type;
+
+remote_type!(
+/// A place where craft can be launched from. Obtained by calling
+/// `SpaceCenter::launch_sites()`.
+object SpaceCenter.LaunchSite {
+    properties: {
+        {
+            Name {
+                /// Returns the name of the launch site, as accepted by
+                /// `SpaceCenter::launch_vessel()`.
+                ///
+                /// **Game Scenes**: All
+                get: name -> String
+            }
+        }
+        {
+            Body {
+                /// Returns the celestial body the launch site is on.
+                ///
+                /// **Game Scenes**: All
+                get: body -> CelestialBody
+            }
+        }
+        {
+            EditorFacility {
+                /// Returns the editor facility whose craft are launched from this site.
+                ///
+                /// **Game Scenes**: All
+                get: editor_facility -> EditorFacility
+            }
+        }
+    }
+});
+
+remote_type!(
+    /// An editor facility.
+    enum EditorFacility {
+        /// Vehicle Assembly Building.
+        VAB = 1,
+        /// Space Plane Hanger.
+        SPH = 2,
+        /// None.
+        None = 0,
+    }
+);
+
+impl<'a> SpaceCenter<'a> {
+    /// Returns the launch site with the given name, or `None` if there is no such site. This
+    /// can be used to check a launch site before passing its name to
+    /// `SpaceCenter::launch_vessel()`.
+    ///
+    /// **Game Scenes**: All
+    pub fn launch_site_with_name(&self, name: &str) -> KrpcResult<Option<LaunchSite<'a>>> {
+        for site in self.launch_sites()? {
+            if site.name()? == name {
+                return Ok(Some(site));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -1,8 +1,8 @@
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteEnum, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 use std::collections::BTreeMap;
 
+mod alarm;
 mod autopilot;
 mod body;
 mod camera;
@@ -10,6 +10,8 @@ mod communication;
 mod contracts;
 mod control;
 mod flight;
+mod frame_graph;
+mod launch_site;
 mod node;
 mod orbit;
 mod parts;
@@ -18,6 +20,7 @@ mod resources;
 mod vessel;
 mod waypoints;
 
+pub use self::alarm::*;
 pub use self::autopilot::*;
 pub use self::body::*;
 pub use self::camera::*;
@@ -25,6 +28,8 @@ pub use self::communication::*;
 pub use self::contracts::*;
 pub use self::control::*;
 pub use self::flight::*;
+pub use self::frame_graph::*;
+pub use self::launch_site::*;
 pub use self::node::*;
 pub use self::orbit::*;
 pub use self::parts::*;
@@ -304,6 +309,22 @@ remote_type!(
                     get: contract_manager -> ContractManager
                 }
             }
+            {
+                AlarmManager {
+                    /// Returns the alarm manager of the stock alarm clock.
+                    ///
+                    /// **Game Scenes**: All
+                    get: alarm_manager -> AlarmManager
+                }
+            }
+            {
+                LaunchSites {
+                    /// Returns the available launch sites.
+                    ///
+                    /// **Game Scenes**: All
+                    get: launch_sites -> Vec<LaunchSite<'a>>
+                }
+            }
         }
         methods: {
             {
@@ -335,7 +356,7 @@ remote_type!(
                 /// * `name` - Name of the vessel to launch. This is the name of the ".craft"
                 /// file in the save directory, without the ".craft" file extension.
                 /// * `launch_site` - Name of the launch site. For example `"LaunchPad"`
-                /// or `"Runway"`.
+                /// or `"Runway"`. The valid names are given by `SpaceCenter::launch_sites()`.
                 /// * `recover` - If true and there is a vessel on the launch site, recover
                 /// it before launching.
                 ///
@@ -345,6 +366,22 @@ remote_type!(
                     LaunchVessel(craft_dir, name, launch_site, recover)
                 }
             }
+            {
+                /// Returns a builder for `SpaceCenter::launch_vessel()` whose `recover` argument is
+                /// optional. If it is not set, the server defaults it to `true`.
+                ///
+                /// **Game Scenes**: All
+                ///
+                /// # Arguments
+                /// * `craft_dir` - Name of the directory in the current saves "Ships"
+                /// directory, that contains the craft file. For example `"VAB"` or `"SPH"`.
+                /// * `name` - Name of the vessel to launch.
+                /// * `launch_site` - Name of the launch site. For example `"LaunchPad"`
+                /// or `"Runway"`. The valid names are given by `SpaceCenter::launch_sites()`.
+                fn launch_vessel_with(craft_dir: &str, name: &str, launch_site: &str) [recover: bool] {
+                    LaunchVessel(craft_dir, name, launch_site)
+                }
+            }
             {
                 /// Launch a new vessel from the VAB onto the launchpad.
                 ///
@@ -467,6 +504,19 @@ remote_type!(
                     WarpTo(ut, max_rails_rate, max_physics_rate)
                 }
             }
+            {
+                /// Returns a builder for `SpaceCenter::warp_to()` whose maximum warp rates are
+                /// optional. If they are not set, the server limits on-rails warp to 100000x and
+                /// physical warp to 2x.
+                ///
+                /// **Game Scenes**: Flight
+                ///
+                /// # Arguments:
+                /// * `ut` - The universal time to warp to, in seconds.
+                fn warp_to_with(ut: f64) [max_rails_rate: f32, max_physics_rate: f32] {
+                    WarpTo(ut)
+                }
+            }
             {
                 /// Converts a position from one reference frame to another.
                 ///
@@ -572,6 +622,42 @@ remote_type!(
                     RaycastPart(position, direction, reference_frame)
                 }
             }
+            {
+                /// Transfers a crew member to a different part.
+                ///
+                /// **Game Scenes**: Flight
+                ///
+                /// # Arguments
+                /// * `crew_member` - The crew member to transfer.
+                /// * `target_part` - The part to move them to.
+                fn transfer_crew(crew_member: &CrewMember, target_part: &Part) {
+                    TransferCrew(crew_member, target_part)
+                }
+            }
+            {
+                /// Creates a kerbal.
+                ///
+                /// **Game Scenes**: All
+                ///
+                /// # Arguments
+                /// * `name` - The name of the kerbal.
+                /// * `job` - The job title, such as "Pilot", "Engineer" or "Scientist".
+                /// * `male` - Whether the kerbal is male, or female.
+                fn create_kerbal(name: &str, job: &str, male: bool) {
+                    CreateKerbal(name, job, male)
+                }
+            }
+            {
+                /// Finds a kerbal by name, or returns `None` if there is no kerbal with that name.
+                ///
+                /// **Game Scenes**: All
+                ///
+                /// # Arguments
+                /// * `name` - The name of the kerbal.
+                fn get_kerbal(name: &str) -> Option<CrewMember> {
+                    GetKerbal(name)
+                }
+            }
         }
     }
 );
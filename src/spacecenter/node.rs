@@ -1,6 +1,5 @@
 use super::{Orbit, ReferenceFrame};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject, Vector3};
+use crate::{remote_type, Vector3};
 
 remote_type!(
 /// Represents a maneuver node.  Can be created using `Control::add_node()`.
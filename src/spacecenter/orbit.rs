@@ -1,6 +1,6 @@
 use super::{CelestialBody, ReferenceFrame};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject, Vector3};
+use crate::client::KrpcResult;
+use crate::{remote_type, Vector3};
 
 remote_type!(
 /// Describes an orbit. For example, the orbit of a vessel or a celestial body.
@@ -77,6 +77,14 @@ object SpaceCenter.Orbit {
                 get: semi_minor_axis -> f64
             }
         }
+        {
+            Period {
+                /// Returns the orbital period, in seconds.
+                ///
+                /// **Game Scenes**: All
+                get: period -> f64
+            }
+        }
         {
             Radius {
                 /// The current radius of the orbit, in meters. This is the distance between
@@ -212,6 +220,15 @@ object SpaceCenter.Orbit {
                 get: time_to_soi_change -> f64
             }
         }
+        {
+            NextOrbit {
+                /// Returns the orbit that follows this one, once the object changes sphere of
+                /// influence, or `None` if the object is not going to change sphere of influence.
+                ///
+                /// **Game Scenes**: All
+                get: next_orbit -> Option<Orbit<'a>>
+            }
+        }
     }
     methods: {
         {
@@ -310,8 +327,8 @@ object SpaceCenter.Orbit {
             ///
             /// # Arguments
             /// * `target` - Target orbit.
-            fn true_anomaly_at_an() -> f64 {
-                TrueAnomalyAtAN()
+            fn true_anomaly_at_an(target: &Orbit) -> f64 {
+                TrueAnomalyAtAN(target)
             }
         }
         {
@@ -321,8 +338,8 @@ object SpaceCenter.Orbit {
             ///
             /// # Arguments
             /// * `target` - Target orbit.
-            fn true_anomaly_at_dn() -> f64 {
-                TrueAnomalyAtDN()
+            fn true_anomaly_at_dn(target: &Orbit) -> f64 {
+                TrueAnomalyAtDN(target)
             }
         }
         {
@@ -423,3 +440,63 @@ object SpaceCenter.Orbit {
         }
     }
 });
+
+impl<'a> Orbit<'a> {
+    /// Returns an iterator over the patched-conic trajectory that starts with this orbit,
+    /// following `next_orbit()` through every upcoming change of sphere of influence.
+    pub fn patches(&self) -> OrbitPatches<'a> {
+        OrbitPatches {
+            next: Some(OrbitPatch {
+                start: 0.0,
+                orbit: self.clone(),
+            }),
+        }
+    }
+}
+
+/// One orbit of a patched-conic trajectory.
+#[derive(Clone, Debug)]
+pub struct OrbitPatch<'a> {
+    /// The time from now at which the object enters this orbit, in seconds. This is zero for
+    /// the current orbit.
+    pub start: f64,
+    pub orbit: Orbit<'a>,
+}
+
+/// An iterator over the orbits of a patched-conic trajectory. Obtained by calling
+/// `Orbit::patches()`.
+///
+/// Each step queries the server for the orbit that follows the current one and the time of
+/// the change of sphere of influence. The iterator ends after the last orbit, or after the
+/// first error.
+#[derive(Debug)]
+pub struct OrbitPatches<'a> {
+    next: Option<OrbitPatch<'a>>,
+}
+
+impl<'a> Iterator for OrbitPatches<'a> {
+    type Item = KrpcResult<OrbitPatch<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let patch = self.next.take()?;
+
+        // The time to a change of sphere of influence is measured from now for every orbit of
+        // the trajectory, not from the start of that orbit.
+        let following = match patch.orbit.next_orbit() {
+            Ok(Some(orbit)) => patch
+                .orbit
+                .time_to_soi_change()
+                .map(|start| Some(OrbitPatch { start, orbit })),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        match following {
+            Ok(next) => {
+                self.next = next;
+                Some(Ok(patch))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use super::{ReferenceFrame, Resources, Vessel};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 mod antenna;
 mod cargo_bay;
@@ -20,7 +19,13 @@ mod radiator;
 mod rcs;
 mod reaction_wheel;
 mod resource_converter;
+mod resource_drain;
 mod resource_harvester;
+mod robotic_controller;
+mod robotic_hinge;
+mod robotic_piston;
+mod robotic_rotation;
+mod robotic_rotor;
 mod sensor;
 mod solar_panel;
 mod thruster;
@@ -44,7 +49,13 @@ pub use self::radiator::*;
 pub use self::rcs::*;
 pub use self::reaction_wheel::*;
 pub use self::resource_converter::*;
+pub use self::resource_drain::*;
 pub use self::resource_harvester::*;
+pub use self::robotic_controller::*;
+pub use self::robotic_hinge::*;
+pub use self::robotic_piston::*;
+pub use self::robotic_rotation::*;
+pub use self::robotic_rotor::*;
 pub use self::sensor::*;
 pub use self::solar_panel::*;
 pub use self::thruster::*;
@@ -224,6 +235,14 @@ object SpaceCenter.Parts {
                 get: resource_converters -> Vec<ResourceConverter>
             }
         }
+        {
+            ResourceDrains {
+                /// Returns a list of all resource drains in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: resource_drains -> Vec<ResourceDrain>
+            }
+        }
         {
             ResourceHarvesters {
                 /// Returns a list of all resource harvesters in the vessel.
@@ -232,6 +251,46 @@ object SpaceCenter.Parts {
                 get: resource_harvesters -> Vec<ResourceHarvester>
             }
         }
+        {
+            RoboticControllers {
+                /// Returns a list of all robotic controllers in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_controllers -> Vec<RoboticController>
+            }
+        }
+        {
+            RoboticHinges {
+                /// Returns a list of all robotic hinges in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_hinges -> Vec<RoboticHinge>
+            }
+        }
+        {
+            RoboticPistons {
+                /// Returns a list of all robotic pistons in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_pistons -> Vec<RoboticPiston>
+            }
+        }
+        {
+            RoboticRotations {
+                /// Returns a list of all robotic rotation servos in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_rotations -> Vec<RoboticRotation>
+            }
+        }
+        {
+            RoboticRotors {
+                /// Returns a list of all robotic rotors in the vessel.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_rotors -> Vec<RoboticRotor>
+            }
+        }
         {
             Sensors {
                 /// Returns a list of all sensors in the vessel.
@@ -824,6 +883,14 @@ object SpaceCenter.Part {
                 get: resource_converter -> Option<ResourceConverter>
             }
         }
+        {
+            ResourceDrain {
+                /// Returns a `ResourceDrain` if this part is a resource drain, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: resource_drain -> Option<ResourceDrain>
+            }
+        }
         {
             ResourceHarvester {
                 /// Returns a `ResourceHarvester` if this part is a resource harvester, otherwise `None`.
@@ -832,6 +899,46 @@ object SpaceCenter.Part {
                 get: resource_harvester -> Option<ResourceHarvester>
             }
         }
+        {
+            RoboticController {
+                /// Returns a `RoboticController` if this part is a robotic controller, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_controller -> Option<RoboticController>
+            }
+        }
+        {
+            RoboticHinge {
+                /// Returns a `RoboticHinge` if this part is a robotic hinge, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_hinge -> Option<RoboticHinge>
+            }
+        }
+        {
+            RoboticPiston {
+                /// Returns a `RoboticPiston` if this part is a robotic piston, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_piston -> Option<RoboticPiston>
+            }
+        }
+        {
+            RoboticRotation {
+                /// Returns a `RoboticRotation` if this part is a robotic rotation servo, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_rotation -> Option<RoboticRotation>
+            }
+        }
+        {
+            RoboticRotor {
+                /// Returns a `RoboticRotor` if this part is a robotic rotor, otherwise `None`.
+                ///
+                /// **Game Scenes**: All
+                get: robotic_rotor -> Option<RoboticRotor>
+            }
+        }
         {
             Sensor {
                 /// Returns a `Sensor` if this part is a sensor, otherwise `None`.
@@ -1,6 +1,5 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// An antenna. Obtained by calling `Part::antenna().`
@@ -1,7 +1,6 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::Vessel;
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// A decoupler. Obtained by calling `Part::decoupler().`
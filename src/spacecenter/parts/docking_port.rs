@@ -1,7 +1,6 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::{ReferenceFrame, Vessel};
-use crate::{remote_type, Quaternion, RemoteEnum, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// A docking port. Obtained by calling `Part::docking_port().`
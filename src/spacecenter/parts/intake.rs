@@ -1,6 +1,5 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// An intake. Obtained by calling `Part::intake().`
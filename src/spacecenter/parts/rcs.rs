@@ -1,7 +1,6 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
 use crate::spacecenter::{Propellant, Thruster};
-use crate::{remote_type, RemoteObject, Vector3};
+use crate::{remote_type, Vector3};
 
 use std::collections::BTreeMap;
 
@@ -1,6 +1,5 @@
 use super::Part;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject, Vector3};
+use crate::{remote_type, Vector3};
 
 remote_type!(
 /// An reaction wheel. Obtained by calling `Part::reaction_wheel().`
@@ -0,0 +1,120 @@
+use super::Part;
+use crate::spacecenter::Resource;
+use crate::remote_type;
+
+remote_type!(
+/// A resource drain, which vents resources out of the vessel. Obtained by calling
+/// `Part::resource_drain()`.
+object SpaceCenter.ResourceDrain {
+    properties: {
+        {
+            Part {
+                /// Returns the part object for this resource drain.
+                ///
+                /// **Game Scenes**: All
+                get: part -> Part
+            }
+        }
+        {
+            AvailableResources {
+                /// Returns the resources that the drain can drain.
+                ///
+                /// **Game Scenes**: All
+                get: available_resources -> Vec<Resource>
+            }
+        }
+        {
+            DrainMode {
+                /// Returns whether the drain drains resources from the part it is on or from
+                /// the whole vessel.
+                ///
+                /// **Game Scenes**: All
+                get: drain_mode -> DrainMode,
+                /// Sets whether the drain drains resources from the part it is on or from the
+                /// whole vessel.
+                ///
+                /// **Game Scenes**: All
+                set: set_drain_mode(DrainMode)
+            }
+        }
+        {
+            MinRate {
+                /// Returns the minimum drain rate, in percent.
+                ///
+                /// **Game Scenes**: All
+                get: min_rate -> f32
+            }
+        }
+        {
+            MaxRate {
+                /// Returns the maximum drain rate, in percent.
+                ///
+                /// **Game Scenes**: All
+                get: max_rate -> f32
+            }
+        }
+        {
+            Rate {
+                /// Returns the drain rate, in percent.
+                ///
+                /// **Game Scenes**: All
+                get: rate -> f32,
+                /// Sets the drain rate, in percent.
+                ///
+                /// **Game Scenes**: All
+                set: set_rate(f32)
+            }
+        }
+    }
+    methods: {
+        {
+            /// Sets whether the given resource is drained.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `resource` - The resource.
+            /// * `enabled` - Whether the resource is drained.
+            fn set_resource(resource: &Resource, enabled: bool) {
+                SetResource(resource, enabled)
+            }
+        }
+        {
+            /// Returns whether the given resource is drained.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `resource` - The resource.
+            fn check_resource(resource: &Resource) -> bool {
+                CheckResource(resource)
+            }
+        }
+        {
+            /// Starts draining the enabled resources.
+            ///
+            /// **Game Scenes**: All
+            fn start() {
+                Start()
+            }
+        }
+        {
+            /// Stops draining.
+            ///
+            /// **Game Scenes**: All
+            fn stop() {
+                Stop()
+            }
+        }
+    }
+});
+
+remote_type!(
+    /// Where a resource drain drains resources from.
+    enum DrainMode {
+        /// Drains only from the part the drain is on.
+        Part = 0,
+        /// Drains from the whole vessel.
+        Vessel = 1,
+    }
+);
@@ -0,0 +1,81 @@
+use super::{Module, Part};
+use crate::remote_type;
+
+remote_type!(
+/// A robotic controller, such as the KAL-1000, which plays back a sequence of key frames on the
+/// fields of other parts. Obtained by calling `Part::robotic_controller()`.
+object SpaceCenter.RoboticController {
+    properties: {
+        {
+            Part {
+                /// Returns the part object for this robotic controller.
+                ///
+                /// **Game Scenes**: All
+                get: part -> Part
+            }
+        }
+    }
+    methods: {
+        {
+            /// Returns whether the controller has a part in one of its axes.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `part` - The part to look for.
+            fn has_part(part: &Part) -> bool {
+                HasPart(part)
+            }
+        }
+        {
+            /// Returns the axes of the controller, each as the title of the part followed by
+            /// the name of its field.
+            ///
+            /// **Game Scenes**: All
+            fn axes() -> Vec<Vec<String>> {
+                Axes()
+            }
+        }
+        {
+            /// Adds an axis to the controller. Returns whether the axis was added.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `module` - The module of the part to control.
+            /// * `field_name` - The name of the field to control.
+            fn add_axis(module: &Module, field_name: &str) -> bool {
+                AddAxis(module, field_name)
+            }
+        }
+        {
+            /// Adds a key frame to an axis of the controller. Returns whether the key frame was
+            /// added.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `module` - The module of the axis.
+            /// * `field_name` - The name of the field of the axis.
+            /// * `time` - The time of the key frame, in seconds.
+            /// * `value` - The value of the field at the key frame.
+            fn add_key_frame(module: &Module, field_name: &str, time: f32, value: f32) -> bool {
+                AddKeyFrame(module, field_name, time, value)
+            }
+        }
+        {
+            /// Removes all key frames from an axis of the controller. Returns whether the axis
+            /// was cleared.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `module` - The module of the axis.
+            /// * `field_name` - The name of the field of the axis.
+            fn clear_axis(module: &Module, field_name: &str) -> bool {
+                ClearAxis(module, field_name)
+            }
+        }
+    }
+}
+);
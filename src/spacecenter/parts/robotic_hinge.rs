@@ -0,0 +1,98 @@
+use super::Part;
+use crate::remote_type;
+
+remote_type!(
+/// A robotic hinge. Obtained by calling `Part::robotic_hinge()`.
+object SpaceCenter.RoboticHinge {
+    properties: {
+        {
+            Part {
+                /// Returns the part object for this hinge.
+                ///
+                /// **Game Scenes**: All
+                get: part -> Part
+            }
+        }
+        {
+            TargetAngle {
+                /// Returns the target angle of the hinge, in degrees.
+                ///
+                /// **Game Scenes**: All
+                get: target_angle -> f32,
+                /// Sets the target angle of the hinge, in degrees.
+                ///
+                /// **Game Scenes**: All
+                set: set_target_angle(f32)
+            }
+        }
+        {
+            CurrentAngle {
+                /// Returns the current angle of the hinge, in degrees.
+                ///
+                /// **Game Scenes**: All
+                get: current_angle -> f32
+            }
+        }
+        {
+            Rate {
+                /// Returns the speed at which the hinge moves towards its target angle, in
+                /// degrees per second.
+                ///
+                /// **Game Scenes**: All
+                get: rate -> f32,
+                /// Sets the speed at which the hinge moves towards its target angle, in degrees
+                /// per second.
+                ///
+                /// **Game Scenes**: All
+                set: set_rate(f32)
+            }
+        }
+        {
+            Damping {
+                /// Returns the damping percentage of the hinge.
+                ///
+                /// **Game Scenes**: All
+                get: damping -> f32,
+                /// Sets the damping percentage of the hinge.
+                ///
+                /// **Game Scenes**: All
+                set: set_damping(f32)
+            }
+        }
+        {
+            Locked {
+                /// Returns whether the hinge is locked.
+                ///
+                /// **Game Scenes**: All
+                get: is_locked -> bool,
+                /// Sets whether the hinge is locked.
+                ///
+                /// **Game Scenes**: All
+                set: set_locked(bool)
+            }
+        }
+        {
+            MotorEngaged {
+                /// Returns whether the motor of the hinge is engaged.
+                ///
+                /// **Game Scenes**: All
+                get: is_motor_engaged -> bool,
+                /// Sets whether the motor of the hinge is engaged.
+                ///
+                /// **Game Scenes**: All
+                set: set_motor_engaged(bool)
+            }
+        }
+    }
+    methods: {
+        {
+            /// Moves the hinge back to its home position.
+            ///
+            /// **Game Scenes**: All
+            fn move_home() {
+                MoveHome()
+            }
+        }
+    }
+}
+);
@@ -0,0 +1,98 @@
+use super::Part;
+use crate::remote_type;
+
+remote_type!(
+/// A robotic piston. Obtained by calling `Part::robotic_piston()`.
+object SpaceCenter.RoboticPiston {
+    properties: {
+        {
+            Part {
+                /// Returns the part object for this piston.
+                ///
+                /// **Game Scenes**: All
+                get: part -> Part
+            }
+        }
+        {
+            TargetExtension {
+                /// Returns the target extension of the piston, in meters.
+                ///
+                /// **Game Scenes**: All
+                get: target_extension -> f32,
+                /// Sets the target extension of the piston, in meters.
+                ///
+                /// **Game Scenes**: All
+                set: set_target_extension(f32)
+            }
+        }
+        {
+            CurrentExtension {
+                /// Returns the current extension of the piston, in meters.
+                ///
+                /// **Game Scenes**: All
+                get: current_extension -> f32
+            }
+        }
+        {
+            Rate {
+                /// Returns the speed at which the piston moves towards its target extension, in
+                /// meters per second.
+                ///
+                /// **Game Scenes**: All
+                get: rate -> f32,
+                /// Sets the speed at which the piston moves towards its target extension, in
+                /// meters per second.
+                ///
+                /// **Game Scenes**: All
+                set: set_rate(f32)
+            }
+        }
+        {
+            Damping {
+                /// Returns the damping percentage of the piston.
+                ///
+                /// **Game Scenes**: All
+                get: damping -> f32,
+                /// Sets the damping percentage of the piston.
+                ///
+                /// **Game Scenes**: All
+                set: set_damping(f32)
+            }
+        }
+        {
+            Locked {
+                /// Returns whether the piston is locked.
+                ///
+                /// **Game Scenes**: All
+                get: is_locked -> bool,
+                /// Sets whether the piston is locked.
+                ///
+                /// **Game Scenes**: All
+                set: set_locked(bool)
+            }
+        }
+        {
+            MotorEngaged {
+                /// Returns whether the motor of the piston is engaged.
+                ///
+                /// **Game Scenes**: All
+                get: is_motor_engaged -> bool,
+                /// Sets whether the motor of the piston is engaged.
+                ///
+                /// **Game Scenes**: All
+                set: set_motor_engaged(bool)
+            }
+        }
+    }
+    methods: {
+        {
+            /// Moves the piston back to its home position.
+            ///
+            /// **Game Scenes**: All
+            fn move_home() {
+                MoveHome()
+            }
+        }
+    }
+}
+);
@@ -0,0 +1,86 @@
+use super::Part;
+use crate::remote_type;
+
+remote_type!(
+/// A robotic rotor. Obtained by calling `Part::robotic_rotor()`.
+object SpaceCenter.RoboticRotor {
+    properties: {
+        {
+            Part {
+                /// Returns the part object for this rotor.
+                ///
+                /// **Game Scenes**: All
+                get: part -> Part
+            }
+        }
+        {
+            TargetRPM {
+                /// Returns the target speed of the rotor, in revolutions per minute.
+                ///
+                /// **Game Scenes**: All
+                get: target_rpm -> f32,
+                /// Sets the target speed of the rotor, in revolutions per minute.
+                ///
+                /// **Game Scenes**: All
+                set: set_target_rpm(f32)
+            }
+        }
+        {
+            CurrentRPM {
+                /// Returns the current speed of the rotor, in revolutions per minute.
+                ///
+                /// **Game Scenes**: All
+                get: current_rpm -> f32
+            }
+        }
+        {
+            Inverted {
+                /// Returns whether the direction of the rotor is inverted.
+                ///
+                /// **Game Scenes**: All
+                get: is_inverted -> bool,
+                /// Sets whether the direction of the rotor is inverted.
+                ///
+                /// **Game Scenes**: All
+                set: set_inverted(bool)
+            }
+        }
+        {
+            TorqueLimit {
+                /// Returns the torque limit percentage of the rotor.
+                ///
+                /// **Game Scenes**: All
+                get: torque_limit -> f32,
+                /// Sets the torque limit percentage of the rotor.
+                ///
+                /// **Game Scenes**: All
+                set: set_torque_limit(f32)
+            }
+        }
+        {
+            Locked {
+                /// Returns whether the rotor is locked.
+                ///
+                /// **Game Scenes**: All
+                get: is_locked -> bool,
+                /// Sets whether the rotor is locked.
+                ///
+                /// **Game Scenes**: All
+                set: set_locked(bool)
+            }
+        }
+        {
+            MotorEngaged {
+                /// Returns whether the motor of the rotor is engaged.
+                ///
+                /// **Game Scenes**: All
+                get: is_motor_engaged -> bool,
+                /// Sets whether the motor of the rotor is engaged.
+                ///
+                /// **Game Scenes**: All
+                set: set_motor_engaged(bool)
+            }
+        }
+    }
+}
+);
@@ -1,5 +1,4 @@
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// Represents a reference frame for positions, rotations and velocities. Contains:
@@ -39,10 +38,10 @@ object SpaceCenter.ReferenceFrame {
                                velocity: Option<Vector3>,
                                angular_velocity: Option<Vector3>) -> ReferenceFrame<'a> {
                 CreateRelative(reference_frame,
-                               position.unwrap_or((0.0, 0.0, 0.0)),
-                               rotation.unwrap_or((0.0, 0.0, 0.0, 1.0)),
-                               velocity.unwrap_or((0.0, 0.0, 0.0)),
-                               angular_velocity.unwrap_or((0.0, 0.0, 0.0)))
+                               position.unwrap_or(Vector3::ZERO),
+                               rotation.unwrap_or(Quaternion::IDENTITY),
+                               velocity.unwrap_or(Vector3::ZERO),
+                               angular_velocity.unwrap_or(Vector3::ZERO))
             }
         }
         {
@@ -68,5 +67,41 @@ object SpaceCenter.ReferenceFrame {
                 CreateHybrid(position, rotation, velocity, angular_velocity)
             }
         }
+        {
+            /// Returns a builder for `ReferenceFrame::create_relative()` whose offsets are
+            /// optional. Any offset that is not set is left for the server to default, which
+            /// applies no offset.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `reference_frame` - The parent reference frame on which to base this reference frame.
+            ///
+            /// The builder's `position`, `rotation`, `velocity` and `angular_velocity` setters
+            /// take the same offsets as `ReferenceFrame::create_relative()`.
+            fn create_relative_with(reference_frame: &ReferenceFrame)
+                [position: Vector3, rotation: Quaternion, velocity: Vector3, angular_velocity: Vector3]
+                -> ReferenceFrame<'a> {
+                CreateRelative(reference_frame)
+            }
+        }
+        {
+            /// Returns a builder for `ReferenceFrame::create_hybrid()` whose rotation, velocity
+            /// and angular velocity frames are optional. Any frame that is not set is taken from
+            /// the `position` frame by the server.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `position` - The reference frame providing the position of the origin.
+            ///
+            /// The builder's `rotation`, `velocity` and `angular_velocity` setters take the
+            /// frames providing the rotation, linear velocity and angular velocity of the frame.
+            fn create_hybrid_with(position: &ReferenceFrame)
+                [rotation: &ReferenceFrame, velocity: &ReferenceFrame, angular_velocity: &ReferenceFrame]
+                -> ReferenceFrame<'a> {
+                CreateHybrid(position)
+            }
+        }
     }
 });
@@ -1,6 +1,5 @@
 use super::{AutoPilot, Comms, Control, Flight, Orbit, Parts, ReferenceFrame, Resources};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteEnum, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// These objects are used to interact with vessels in KSP. This includes getting orbital and
@@ -434,6 +433,20 @@ object SpaceCenter.Vessel {
                 Flight(reference_frame)
             }
         }
+        {
+            /// Returns a builder for `Vessel::flight()` whose reference frame argument is
+            /// optional. If it is not set, the server uses the vessel’s surface reference frame.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Example
+            /// ```ignore
+            /// let flight = vessel.flight_with().reference_frame(&reference_frame).call()?;
+            /// ```
+            fn flight_with() [reference_frame: &ReferenceFrame] -> Flight<'a> {
+                Flight()
+            }
+        }
         {
             /// Returns a `Resources` object, that can used to get information about resources
             /// stored in a given stage.
@@ -449,6 +462,18 @@ object SpaceCenter.Vessel {
                 ResourcesInDecoupleStage(stage, cumulative)
             }
         }
+        {
+            /// Returns a builder for `Vessel::resources_in_decouple_stage()` whose `cumulative`
+            /// argument is optional. If it is not set, the server defaults it to `true`.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `stage` - Get resources for parts that are decoupled in this stage.
+            fn resources_in_decouple_stage_with(stage: i32) [cumulative: bool] -> Resources<'a> {
+                ResourcesInDecoupleStage(stage)
+            }
+        }
         {
             /// Returns the position of the center of mass of the vessel, in the given reference frame.
             ///
@@ -536,6 +561,44 @@ object SpaceCenter.Vessel {
                 AngularVelocity(reference_frame)
             }
         }
+        {
+            /// Gets the total available thrust that can be produced by the vessel's active
+            /// engines, in Newtons, at the given pressure, in atmospheres. Returns zero if the
+            /// vessel has no active engines.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `pressure` - Atmospheric pressure, in atmospheres.
+            fn available_thrust_at(pressure: f64) -> f32 {
+                AvailableThrustAt(pressure)
+            }
+        }
+        {
+            /// Gets the total maximum thrust that can be produced by the vessel's active engines,
+            /// in Newtons, at the given pressure, in atmospheres. Returns zero if the vessel has no
+            /// active engines.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `pressure` - Atmospheric pressure, in atmospheres.
+            fn max_thrust_at(pressure: f64) -> f32 {
+                MaxThrustAt(pressure)
+            }
+        }
+        {
+            /// Gets the combined specific impulse of all active engines, in seconds, at the given
+            /// pressure, in atmospheres. Returns zero if the vessel has no active engines.
+            ///
+            /// **Game Scenes**: Flight
+            ///
+            /// # Arguments
+            /// * `pressure` - Atmospheric pressure, in atmospheres.
+            fn specific_impulse_at(pressure: f64) -> f32 {
+                SpecificImpulseAt(pressure)
+            }
+        }
     }
 });
 
@@ -571,6 +634,62 @@ object SpaceCenter.CrewMember {
                 get: is_on_mission -> bool
             }
         }
+        {
+            Trait {
+                /// Returns the crew member's job title, such as "Pilot", "Engineer" or "Scientist".
+                ///
+                /// **Game Scenes**: All
+                get: crew_trait -> String
+            }
+        }
+        {
+            Gender {
+                /// Returns the crew member's gender.
+                ///
+                /// **Game Scenes**: All
+                get: gender -> CrewMemberGender
+            }
+        }
+        {
+            RosterStatus {
+                /// Returns the crew member's status in the astronaut roster.
+                ///
+                /// **Game Scenes**: All
+                get: roster_status -> RosterStatus
+            }
+        }
+        {
+            SuitType {
+                /// Returns the crew member's suit type.
+                ///
+                /// **Game Scenes**: All
+                get: suit_type -> SuitType
+            }
+        }
+        {
+            CareerLogFlights {
+                /// Returns the flight IDs of each entry in the crew member's career log.
+                ///
+                /// **Game Scenes**: All
+                get: career_log_flights -> Vec<i32>
+            }
+        }
+        {
+            CareerLogTypes {
+                /// Returns the type of each entry in the crew member's career log.
+                ///
+                /// **Game Scenes**: All
+                get: career_log_types -> Vec<String>
+            }
+        }
+        {
+            CareerLogTargets {
+                /// Returns the body name of each entry in the crew member's career log.
+                ///
+                /// **Game Scenes**: All
+                get: career_log_targets -> Vec<String>
+            }
+        }
         {
             Courage {
                 /// Returns the crew member's courage.
@@ -684,3 +803,39 @@ remote_type!(
         Unowned = 3,
     }
 );
+
+remote_type!(
+    /// The gender of a crew member.
+    enum CrewMemberGender {
+        Male = 0,
+        Female = 1,
+    }
+);
+
+remote_type!(
+    /// The status of a crew member in the astronaut roster.
+    enum RosterStatus {
+        /// Available to be assigned to a vessel.
+        Available = 0,
+        /// Assigned to a vessel.
+        Assigned = 1,
+        /// Dead.
+        Dead = 2,
+        /// Missing, and will respawn after some time.
+        Missing = 3,
+    }
+);
+
+remote_type!(
+    /// The suit worn by a crew member.
+    enum SuitType {
+        /// The default suit.
+        Default = 0,
+        /// The vintage suit, from the Making History expansion.
+        Vintage = 1,
+        /// The futuristic suit, from the Breaking Ground expansion.
+        Future = 2,
+        /// The slim suit.
+        Slim = 3,
+    }
+);
@@ -1,6 +1,5 @@
 use super::{CelestialBody, Contract};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 use std::collections::BTreeMap;
 
@@ -0,0 +1,241 @@
+use super::{EngineModel, PartModel, ResourceModel, Stage, VesselModel};
+use crate::client::{decode_space_center_result, Connection, KrpcResult, ProcedureCall};
+use crate::spacecenter::{
+    CelestialBody, Control, Engine, Part, Parts, Resource, ResourceFlowMode, Resources, Vessel,
+};
+use crate::RemoteObject;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// The calls made for each part, in the order their results are decoded.
+fn part_calls(part: &Part) -> KrpcResult<Vec<ProcedureCall>> {
+    let calls = part.call();
+    Ok(vec![
+        calls.dry_mass()?,
+        calls.is_crossfeed_capable()?,
+        calls.is_fuel_line()?,
+        calls.parent()?,
+        calls.children()?,
+        calls.fuel_lines_from()?,
+        calls.fuel_lines_to()?,
+        calls.engine()?,
+    ])
+}
+
+/// The calls made for each engine, in the order their results are decoded.
+fn engine_calls(engine: &Engine) -> KrpcResult<Vec<ProcedureCall>> {
+    let calls = engine.call();
+    Ok(vec![
+        calls.max_vacuum_thrust()?,
+        calls.thrust_limit()?,
+        calls.vacuum_isp()?,
+        calls.kerbin_sea_level_isp()?,
+        calls.propellant_ratios()?,
+    ])
+}
+
+/// The calls made for each resource, in the order their results are decoded.
+fn resource_calls(resource: &Resource) -> KrpcResult<Vec<ProcedureCall>> {
+    let calls = resource.call();
+    Ok(vec![
+        calls.part()?,
+        calls.name()?,
+        calls.amount()?,
+        calls.density()?,
+        calls.flow_mode()?,
+    ])
+}
+
+impl VesselModel {
+    /// Fetches a snapshot of `vessel`, with a handful of requests however many parts it has.
+    ///
+    /// The parts and their resources are listed stage by stage with
+    /// `Parts::in_decoupler_stage` and `Vessel::resources_in_decouple_stage`. Engines are
+    /// modelled with their maximum vacuum thrust, scaled by their thrust limiter, as the
+    /// current thrust depends on the atmosphere and is zero for engines without fuel.
+    pub fn fetch(connection: &Connection, vessel: &Vessel) -> KrpcResult<Self> {
+        let calls = vessel.call();
+        let results = &mut connection
+            .invoke_batch(vec![calls.parts()?, calls.control()?])?
+            .into_iter();
+        let parts: Parts = decode_space_center_result(results, connection, "Vessel_get_Parts")?;
+        let control: Control =
+            decode_space_center_result(results, connection, "Vessel_get_Control")?;
+        let current_stage = control.current_stage()?;
+
+        // The parts and resources decoupled in each stage, including those never decoupled.
+        let stages: Vec<i32> = (-1..=current_stage).collect();
+        let mut batch = Vec::new();
+        for &stage in stages.iter() {
+            batch.push(parts.call().in_decoupler_stage(stage)?);
+            batch.push(calls.resources_in_decouple_stage(stage, false)?);
+        }
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let mut all_parts: Vec<(Part, i32)> = Vec::new();
+        let mut all_resources: Vec<Resources> = Vec::new();
+        for &stage in stages.iter() {
+            let in_stage: Vec<Part> =
+                decode_space_center_result(results, connection, "Parts_InDecouplerStage")?;
+            all_parts.extend(in_stage.into_iter().map(|part| (part, stage)));
+            all_resources.push(decode_space_center_result(
+                results,
+                connection,
+                "Vessel_ResourcesInDecoupleStage",
+            )?);
+        }
+        let indices: HashMap<u64, usize> = all_parts
+            .iter()
+            .enumerate()
+            .map(|(index, (part, _))| (part.id(), index))
+            .collect();
+
+        let mut batch = Vec::new();
+        for (part, _) in all_parts.iter() {
+            batch.extend(part_calls(part)?);
+        }
+        for resources in all_resources.iter() {
+            batch.push(resources.call().all()?);
+        }
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        let mut models = Vec::new();
+        let mut engines = Vec::new();
+        let mut fuel_lines_to = Vec::new();
+        for (index, &(_, decouple_stage)) in all_parts.iter().enumerate() {
+            let dry_mass: f64 =
+                decode_space_center_result(results, connection, "Part_get_DryMass")?;
+            let crossfeed: bool =
+                decode_space_center_result(results, connection, "Part_get_Crossfeed")?;
+            let fuel_line: bool =
+                decode_space_center_result(results, connection, "Part_get_IsFuelLine")?;
+            let parent: Option<Part> =
+                decode_space_center_result(results, connection, "Part_get_Parent")?;
+            let children: Vec<Part> =
+                decode_space_center_result(results, connection, "Part_get_Children")?;
+            let lines_from: Vec<Part> =
+                decode_space_center_result(results, connection, "Part_get_FuelLinesFrom")?;
+            let lines_to: Vec<Part> =
+                decode_space_center_result(results, connection, "Part_get_FuelLinesTo")?;
+            let engine: Option<Engine> =
+                decode_space_center_result(results, connection, "Part_get_Engine")?;
+
+            let mut draws_from: Vec<usize> = parent
+                .iter()
+                .chain(children.iter())
+                .chain(lines_from.iter())
+                .filter_map(|part| indices.get(&part.id()).cloned())
+                .collect();
+            draws_from.sort();
+            draws_from.dedup();
+            fuel_lines_to.push(lines_to);
+            if let Some(engine) = engine {
+                engines.push((index, engine));
+            }
+            models.push(PartModel {
+                decouple_stage,
+                dry_mass,
+                resources: BTreeMap::new(),
+                // Fuel lines are attached to the part they draw from, but do not let anything
+                // flow back to it from the part they feed.
+                crossfeed: crossfeed && !fuel_line,
+                draws_from,
+                engine: None,
+            });
+        }
+        let mut stage_resources = Vec::new();
+        for _ in all_resources.iter() {
+            let resources: Vec<Resource> =
+                decode_space_center_result(results, connection, "Resources_get_All")?;
+            stage_resources.extend(resources);
+        }
+
+        // A fuel line from one part to another is seen from both ends, so add the ends that
+        // were only seen from the part the fuel flows from.
+        for (from, lines_to) in fuel_lines_to.iter().enumerate() {
+            for part in lines_to.iter() {
+                if let Some(&to) = indices.get(&part.id()) {
+                    if !models[to].draws_from.contains(&from) {
+                        models[to].draws_from.push(from);
+                    }
+                }
+            }
+        }
+
+        let mut batch = Vec::new();
+        for (index, engine) in engines.iter() {
+            batch.extend(engine_calls(engine)?);
+            batch.push(all_parts[*index].0.call().stage()?);
+        }
+        for resource in stage_resources.iter() {
+            batch.extend(resource_calls(resource)?);
+        }
+        let results = &mut connection.invoke_batch(batch)?.into_iter();
+        for &(index, _) in engines.iter() {
+            let thrust: f32 =
+                decode_space_center_result(results, connection, "Engine_get_MaxVacuumThrust")?;
+            let limit: f32 =
+                decode_space_center_result(results, connection, "Engine_get_ThrustLimit")?;
+            let vacuum_isp: f32 = decode_space_center_result(
+                results,
+                connection,
+                "Engine_get_VacuumSpecificImpulse",
+            )?;
+            let sea_level_isp: f32 = decode_space_center_result(
+                results,
+                connection,
+                "Engine_get_KerbinSeaLevelSpecificImpulse",
+            )?;
+            let ratios: BTreeMap<String, f32> =
+                decode_space_center_result(results, connection, "Engine_get_PropellantRatios")?;
+            let stage: i32 = decode_space_center_result(results, connection, "Part_get_Stage")?;
+            models[index].engine = Some(EngineModel {
+                stage,
+                vacuum_thrust: f64::from(thrust) * f64::from(limit),
+                vacuum_isp: f64::from(vacuum_isp),
+                sea_level_isp: f64::from(sea_level_isp),
+                propellants: ratios
+                    .into_iter()
+                    .map(|(name, ratio)| (name, f64::from(ratio)))
+                    .collect(),
+            });
+        }
+        let mut resources = BTreeMap::new();
+        for _ in stage_resources.iter() {
+            let part: Part = decode_space_center_result(results, connection, "Resource_get_Part")?;
+            let name: String =
+                decode_space_center_result(results, connection, "Resource_get_Name")?;
+            let amount: f32 =
+                decode_space_center_result(results, connection, "Resource_get_Amount")?;
+            let density: f32 =
+                decode_space_center_result(results, connection, "Resource_get_Density")?;
+            let flow_mode: ResourceFlowMode =
+                decode_space_center_result(results, connection, "Resource_get_FlowMode")?;
+            if let Some(&index) = indices.get(&part.id()) {
+                *models[index].resources.entry(name.clone()).or_insert(0.0) += f64::from(amount);
+            }
+            resources.insert(
+                name,
+                ResourceModel {
+                    density: f64::from(density),
+                    flow_mode,
+                },
+            );
+        }
+
+        Ok(VesselModel {
+            parts: models,
+            resources,
+            current_stage,
+        })
+    }
+}
+
+/// Works out the staging table of `vessel`, from the current stage down to stage 0, with
+/// thrust to weight ratios against the surface gravity of `body`. See `VesselModel::stages`.
+pub fn stage_table(
+    connection: &Connection,
+    vessel: &Vessel,
+    body: &CelestialBody,
+) -> KrpcResult<Vec<Stage>> {
+    let model = VesselModel::fetch(connection, vessel)?;
+    Ok(model.stages(f64::from(body.surface_gravity()?)))
+}
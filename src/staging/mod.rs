@@ -0,0 +1,13 @@
+//! Staging tables worked out on the client.
+//!
+//! A `VesselModel` is a snapshot of the parts, resources and engines of a vessel. From it,
+//! `VesselModel::stages` simulates burning each stage in turn to give the masses, delta-v,
+//! burn time and thrust to weight ratio of every stage, taking into account which tanks each
+//! engine can draw from through crossfeed and fuel lines. `stage_table` does both for a vessel
+//! in flight.
+
+mod fetch;
+mod model;
+
+pub use self::fetch::*;
+pub use self::model::*;
@@ -0,0 +1,339 @@
+use crate::guidance::STANDARD_GRAVITY;
+use crate::spacecenter::ResourceFlowMode;
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// The amount of a resource, in units, below which a tank counts as empty.
+const EMPTY: f64 = 1e-9;
+
+/// A snapshot of the parts of a vessel that matter for staging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VesselModel {
+    pub parts: Vec<PartModel>,
+    /// The resources held by the parts or burnt by the engines, by name.
+    pub resources: BTreeMap<String, ResourceModel>,
+    /// The stage that is currently active, as given by `Control::current_stage`.
+    pub current_stage: i32,
+}
+
+/// A part of a `VesselModel`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartModel {
+    /// The stage in which the part is decoupled, or -1 if it is never decoupled.
+    pub decouple_stage: i32,
+    /// The mass of the part without its resources, in kg.
+    pub dry_mass: f64,
+    /// The amount of each resource in the part, in units.
+    pub resources: BTreeMap<String, f64>,
+    /// Whether resources that flow between adjacent parts can flow through this part.
+    pub crossfeed: bool,
+    /// The indices of the parts that this part can draw resources from directly: the parts it
+    /// is attached to and the parts with fuel lines into it.
+    pub draws_from: Vec<usize>,
+    pub engine: Option<EngineModel>,
+}
+
+/// The engine of a `PartModel`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineModel {
+    /// The stage in which the engine is activated.
+    pub stage: i32,
+    /// The thrust in a vacuum at full throttle, with the thrust limiter applied, in Newtons.
+    pub vacuum_thrust: f64,
+    /// The specific impulse in a vacuum, in seconds.
+    pub vacuum_isp: f64,
+    /// The specific impulse at sea level on Kerbin, in seconds.
+    pub sea_level_isp: f64,
+    /// The ratios of the propellants burnt by the engine, in units, by resource name.
+    pub propellants: BTreeMap<String, f64>,
+}
+
+/// How a resource is stored and moved.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ResourceModel {
+    /// The mass of one unit of the resource, in kg.
+    pub density: f64,
+    pub flow_mode: ResourceFlowMode,
+}
+
+/// A row of a staging table.
+///
+/// The masses are of the vessel at the start and end of the stage. The end is when the
+/// engines that are dropped by the next stage run dry or, if there are none, when all of the
+/// engines do.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stage {
+    pub stage: i32,
+    /// The mass at the start of the stage, in kg.
+    pub wet_mass: f64,
+    /// The mass at the end of the stage, in kg.
+    pub dry_mass: f64,
+    /// The change in velocity in a vacuum, in m/s.
+    pub vacuum_delta_v: f64,
+    /// The change in velocity at sea level on Kerbin, in m/s.
+    pub sea_level_delta_v: f64,
+    /// The time taken to burn the stage at full throttle, in seconds.
+    pub burn_time: f64,
+    /// The thrust to weight ratio at the start of the stage in a vacuum.
+    pub vacuum_twr: f64,
+    /// The thrust to weight ratio at the start of the stage at sea level.
+    pub sea_level_twr: f64,
+}
+
+impl VesselModel {
+    /// Works out the staging table, from the current stage down to stage 0. The thrust to
+    /// weight ratios are against `surface_gravity`, in m/s².
+    ///
+    /// Each stage is simulated burning at full throttle. Engines burn their propellants from
+    /// the parts they can reach, starting with the parts that will be decoupled soonest, so
+    /// that radial boosters with fuel lines into the core stage are drained first. Resources
+    /// that flow between adjacent parts only pass through parts that allow crossfeed.
+    pub fn stages(&self, surface_gravity: f64) -> Vec<Stage> {
+        let mut amounts: Vec<BTreeMap<String, f64>> = self
+            .parts
+            .iter()
+            .map(|part| part.resources.clone())
+            .collect();
+        (0..=self.current_stage)
+            .rev()
+            .map(|stage| self.stage(stage, &mut amounts, surface_gravity))
+            .collect()
+    }
+
+    /// Burns `stage`, using up the resources in `amounts`.
+    fn stage(
+        &self,
+        stage: i32,
+        amounts: &mut [BTreeMap<String, f64>],
+        surface_gravity: f64,
+    ) -> Stage {
+        let present: Vec<bool> = self
+            .parts
+            .iter()
+            .map(|part| part.decouple_stage < stage)
+            .collect();
+        let engines: Vec<ActiveEngine> = self
+            .parts
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| present[index])
+            .filter_map(|(index, part)| part.engine.as_ref().map(|engine| (index, engine)))
+            .filter(|&(_, engine)| {
+                engine.stage >= stage && engine.vacuum_thrust > 0.0 && engine.vacuum_isp > 0.0
+            })
+            .map(|(index, engine)| ActiveEngine {
+                part: index,
+                engine,
+                sources: self.sources(index, engine, &present),
+            })
+            .collect();
+
+        // The stage ends when the engines dropped by the next stage run dry, if there are any.
+        let dropped: Vec<bool> = engines
+            .iter()
+            .map(|active| self.parts[active.part].decouple_stage == stage - 1)
+            .collect();
+        let watched: Vec<bool> = if dropped.contains(&true) {
+            dropped
+        } else {
+            vec![true; engines.len()]
+        };
+
+        let wet_mass = self.mass(&present, amounts);
+        let mut row = Stage {
+            stage,
+            wet_mass,
+            dry_mass: wet_mass,
+            ..Stage::default()
+        };
+        loop {
+            let fueled: Vec<usize> = (0..engines.len())
+                .filter(|&e| {
+                    engines[e].sources.iter().all(|(name, sources)| {
+                        sources
+                            .iter()
+                            .any(|&part| amount(amounts, part, name) > EMPTY)
+                    })
+                })
+                .collect();
+            if !fueled.iter().any(|&e| watched[e]) {
+                break;
+            }
+
+            // The rate at which each tank is drained, in units per second.
+            let mut drains: BTreeMap<(usize, &str), f64> = BTreeMap::new();
+            let (mut vacuum_thrust, mut sea_level_thrust, mut mass_flow) = (0.0, 0.0, 0.0);
+            for &e in fueled.iter() {
+                let ActiveEngine {
+                    engine,
+                    ref sources,
+                    ..
+                } = engines[e];
+                let flow = engine.vacuum_thrust / (engine.vacuum_isp * STANDARD_GRAVITY);
+                let unit_mass: f64 = engine
+                    .propellants
+                    .iter()
+                    .map(|(name, ratio)| ratio * self.density(name))
+                    .sum();
+                if unit_mass <= 0.0 {
+                    continue;
+                }
+                vacuum_thrust += engine.vacuum_thrust;
+                sea_level_thrust += engine.vacuum_thrust * engine.sea_level_isp / engine.vacuum_isp;
+                mass_flow += flow;
+                for (&name, sources) in sources.iter() {
+                    let rate = flow / unit_mass * engine.propellants[name];
+                    let tanks = first_to_drain(&self.parts, amounts, sources, name);
+                    let total: f64 = tanks.iter().map(|&part| amount(amounts, part, name)).sum();
+                    for part in tanks {
+                        *drains.entry((part, name)).or_insert(0.0) +=
+                            rate * amount(amounts, part, name) / total;
+                    }
+                }
+            }
+            if mass_flow <= 0.0 {
+                break;
+            }
+            if row.burn_time == 0.0 {
+                row.vacuum_twr = vacuum_thrust / (wet_mass * surface_gravity);
+                row.sea_level_twr = sea_level_thrust / (wet_mass * surface_gravity);
+            }
+
+            // Burn until the next tank runs dry.
+            let (empties, time) = drains
+                .iter()
+                .map(|(&(part, name), rate)| ((part, name), amount(amounts, part, name) / rate))
+                .fold((None, f64::INFINITY), |best, (tank, time)| {
+                    if time < best.1 {
+                        (Some(tank), time)
+                    } else {
+                        best
+                    }
+                });
+            let start_mass = self.mass(&present, amounts);
+            for (&(part, name), rate) in drains.iter() {
+                let left = amounts[part].get_mut(name).unwrap();
+                *left -= rate * time;
+                if *left <= EMPTY || empties == Some((part, name)) {
+                    *left = 0.0;
+                }
+            }
+            let end_mass = self.mass(&present, amounts);
+
+            let mass_ratio = (start_mass / end_mass).ln();
+            row.vacuum_delta_v += vacuum_thrust / mass_flow * mass_ratio;
+            row.sea_level_delta_v += sea_level_thrust / mass_flow * mass_ratio;
+            row.burn_time += time;
+            row.dry_mass = end_mass;
+        }
+        row
+    }
+
+    /// Returns the parts that each propellant of the engine on part `index` can be drawn from.
+    fn sources<'m>(
+        &self,
+        index: usize,
+        engine: &'m EngineModel,
+        present: &[bool],
+    ) -> BTreeMap<&'m str, Vec<usize>> {
+        let mut reachable: Option<Vec<usize>> = None;
+        engine
+            .propellants
+            .iter()
+            .filter(|&(_, &ratio)| ratio > 0.0)
+            .map(|(name, _)| {
+                let flow_mode = self
+                    .resources
+                    .get(name)
+                    .map_or(ResourceFlowMode::None, |resource| resource.flow_mode);
+                let parts = match flow_mode {
+                    ResourceFlowMode::None => vec![index],
+                    ResourceFlowMode::Vessel | ResourceFlowMode::Stage => (0..self.parts.len())
+                        .filter(|&part| present[part])
+                        .collect(),
+                    ResourceFlowMode::Adjacent => reachable
+                        .get_or_insert_with(|| self.crossfeed(index, present))
+                        .clone(),
+                };
+                (name.as_str(), parts)
+            })
+            .collect()
+    }
+
+    /// Returns the parts that resources can flow to part `index` from, through parts that
+    /// allow crossfeed.
+    fn crossfeed(&self, index: usize, present: &[bool]) -> Vec<usize> {
+        let mut visited = vec![false; self.parts.len()];
+        let mut queue = VecDeque::new();
+        visited[index] = true;
+        queue.push_back(index);
+        while let Some(part) = queue.pop_front() {
+            for &next in self.parts[part].draws_from.iter() {
+                if present[next] && !visited[next] {
+                    visited[next] = true;
+                    if self.parts[next].crossfeed {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        (0..self.parts.len())
+            .filter(|&part| visited[part])
+            .collect()
+    }
+
+    fn density(&self, name: &str) -> f64 {
+        self.resources
+            .get(name)
+            .map_or(0.0, |resource| resource.density)
+    }
+
+    fn mass(&self, present: &[bool], amounts: &[BTreeMap<String, f64>]) -> f64 {
+        self.parts
+            .iter()
+            .zip(amounts)
+            .zip(present)
+            .filter(|&(_, &present)| present)
+            .map(|((part, amounts), _)| {
+                part.dry_mass
+                    + amounts
+                        .iter()
+                        .map(|(name, amount)| amount * self.density(name))
+                        .sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+/// An engine that is active in the stage being burnt.
+struct ActiveEngine<'m> {
+    part: usize,
+    engine: &'m EngineModel,
+    /// The parts that each propellant can be drawn from.
+    sources: BTreeMap<&'m str, Vec<usize>>,
+}
+
+fn amount(amounts: &[BTreeMap<String, f64>], part: usize, name: &str) -> f64 {
+    amounts[part].get(name).cloned().unwrap_or(0.0)
+}
+
+/// Returns the tanks of `sources` that a resource is drawn from first: those holding some of
+/// it that are decoupled soonest.
+fn first_to_drain(
+    parts: &[PartModel],
+    amounts: &[BTreeMap<String, f64>],
+    sources: &[usize],
+    name: &str,
+) -> Vec<usize> {
+    let filled: Vec<usize> = sources
+        .iter()
+        .cloned()
+        .filter(|&part| amount(amounts, part, name) > EMPTY)
+        .collect();
+    let soonest = filled.iter().map(|&part| parts[part].decouple_stage).max();
+    filled
+        .into_iter()
+        .filter(|&part| Some(parts[part].decouple_stage) == soonest)
+        .collect()
+}
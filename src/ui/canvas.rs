@@ -1,6 +1,5 @@
 use super::{Button, InputField, Panel, RectTransform, Text};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// A canvas for user interface elements
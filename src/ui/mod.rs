@@ -1,5 +1,4 @@
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, Vector3};
+use crate::{remote_type, Vector3};
 
 mod button;
 mod canvas;
@@ -74,6 +73,28 @@ service UI {
                 Clear(client_only)
             }
         }
+        {
+            /// Returns a builder for `UI::message()` where everything but the content is optional.
+            /// If they are not set, the message is shown for 1 second at the top center of the
+            /// screen, in yellow, with a size of 20.
+            ///
+            /// **Game Scenes**: All
+            ///
+            /// # Arguments
+            /// * `content` – Message content.
+            fn message_with(content: &str) [duration: f32, position: MessagePosition, color: Vector3, size: f32] {
+                Message(content)
+            }
+        }
+        {
+            /// Returns a builder for `UI::clear()` whose `client_only` argument is optional.
+            /// If it is not set, user interface elements from all clients are removed.
+            ///
+            /// **Game Scenes**: All
+            fn clear_with() [client_only: bool] {
+                Clear()
+            }
+        }
     }
 });
 
@@ -1,6 +1,5 @@
 use super::{Button, InputField, RectTransform, Text};
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteObject};
+use crate::remote_type;
 
 remote_type!(
 /// A container for user interface elements.
@@ -1,5 +1,4 @@
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, Quaternion, RemoteObject, Vector3};
+use crate::{remote_type, Quaternion, Vector3};
 
 remote_type!(
 /// A Unity engine Rect Transform for a UI object. See the
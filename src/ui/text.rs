@@ -1,6 +1,5 @@
 use super::RectTransform;
-use crate::codec::{Decode, Encode};
-use crate::{remote_type, RemoteEnum, RemoteObject, Vector3};
+use crate::{remote_type, Vector3};
 
 remote_type!(
 /// A text label.
@@ -0,0 +1,304 @@
+//! Checks that values survive encoding and decoding, that malformed input is an error, and
+//! that the errors of the generated bindings name the procedure and value that failed.
+mod common;
+
+use krpc_bindings::client::Connection;
+use krpc_bindings::codec::{
+    encode_argument, Bytes, CodecError, CodecResult, Decode, DecodeRef, Encode, Error,
+    ProcedureCodecError, ProcedureValue,
+};
+use krpc_bindings::spacecenter::{SpaceCenter, Vessel};
+use krpc_bindings::RemoteObject;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Debug;
+
+fn round_trip<'a, T>(value: T, connection: &'a Connection)
+where
+    T: Encode + Decode<'a> + Debug + PartialEq,
+{
+    let bytes = value.encode().unwrap();
+    assert_eq!(T::decode(&bytes, connection).unwrap(), value);
+}
+
+fn codec_error(error: Error) -> CodecError {
+    match error.downcast::<CodecError>() {
+        Ok(error) => error,
+        Err(error) => panic!("expected a codec error, got {}", error),
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Named {
+    id: u32,
+    name: String,
+    position: (f64, f64, f64),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Unnamed(bool, i64);
+
+#[derive(Debug, Copy, Clone, PartialEq, Encode, Decode)]
+enum Mode {
+    Off,
+    On = 5,
+    Auto,
+}
+
+#[test]
+fn derived_structs_and_enums() {
+    let connection = &common::connection();
+    round_trip(
+        Named {
+            id: 7,
+            name: "Jebediah".to_string(),
+            position: (1.0, -2.5, 1e9),
+        },
+        connection,
+    );
+    round_trip(Unnamed(true, -42), connection);
+    for &mode in [Mode::Off, Mode::On, Mode::Auto].iter() {
+        round_trip(mode, connection);
+    }
+
+    // Structs are tuples of their fields, and enums the value of their discriminant.
+    assert_eq!(
+        Unnamed(true, -42).encode().unwrap(),
+        (true, -42i64).encode().unwrap()
+    );
+    assert_eq!(Mode::Auto.encode().unwrap(), 6i64.encode().unwrap());
+}
+
+#[test]
+fn derived_decode_rejects_bad_input() {
+    let connection = &common::connection();
+
+    match codec_error(Mode::decode(&3i64.encode().unwrap(), connection).unwrap_err()) {
+        CodecError::InvalidEnumValue { value, .. } => assert_eq!(value, 3),
+        error => panic!("unexpected error {:?}", error),
+    }
+
+    let bytes = (1u32, "Bill".to_string()).encode().unwrap();
+    match codec_error(Named::decode(&bytes, connection).unwrap_err()) {
+        CodecError::MismatchedTupleLength {
+            actual, expected, ..
+        } => assert_eq!((actual, expected), (2, 3)),
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+#[test]
+fn bytes_and_lists_of_bytes() {
+    let connection = &common::connection();
+    let raw = vec![0u8, 1, 127, 128, 255];
+    round_trip(Bytes(raw.clone()), connection);
+    round_trip(raw.clone(), connection);
+    round_trip(Bytes(Vec::new()), connection);
+
+    // `Bytes` is a length-delimited value, and `Vec<u8>` a `List` of integers.
+    let bytes = Bytes(raw.clone()).encode().unwrap();
+    assert_eq!(bytes[0] as usize, raw.len());
+    assert_eq!(&bytes[1..], &raw[..]);
+    assert_ne!(raw.encode().unwrap(), bytes);
+}
+
+#[test]
+fn borrowed_strings_and_bytes() {
+    let text = "Kerbal Space Program".to_string().encode().unwrap();
+    let decoded = <&str>::decode_ref(&text).unwrap();
+    assert_eq!(decoded, "Kerbal Space Program");
+    // The decoded value points into the encoded bytes, rather than a copy of them.
+    assert_eq!(decoded.as_ptr(), text[1..].as_ptr());
+
+    let bytes = Bytes(vec![9, 8, 7]).encode().unwrap();
+    assert_eq!(<&[u8]>::decode_ref(&bytes).unwrap(), &[9, 8, 7]);
+    assert_eq!(<&[u8]>::decode_ref(&[0]).unwrap(), &[] as &[u8]);
+
+    // Truncated, with trailing bytes, and not UTF-8.
+    let truncated = text[..text.len() - 1].to_vec();
+    let trailing = [&text[..], &[0]].concat();
+    for bad in [truncated, trailing, vec![2, 0xc3, 0x28]].iter() {
+        match codec_error(<&str>::decode_ref(bad).unwrap_err()) {
+            CodecError::Malformed { .. } => {}
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+}
+
+#[test]
+fn optional_primitives() {
+    let connection = &common::connection();
+    round_trip(Some(true), connection);
+    round_trip(Some(-7i32), connection);
+    round_trip(Some(3.5f64), connection);
+    round_trip(Some("Valentina".to_string()), connection);
+    round_trip(Some(Bytes(vec![1, 2])), connection);
+    round_trip(None::<u64>, connection);
+    round_trip(None::<String>, connection);
+    assert!(None::<f32>.encode().unwrap().is_empty());
+}
+
+#[test]
+fn tuples_and_arrays() {
+    let connection = &common::connection();
+    round_trip((1u8,), connection);
+    round_trip(
+        (
+            true,
+            1i32,
+            2i64,
+            3u8,
+            4u32,
+            5u64,
+            6.5f32,
+            7.5f64,
+            "eight".to_string(),
+            Bytes(vec![9]),
+            vec![10u32, 11],
+            (12i32, 13i32),
+        ),
+        connection,
+    );
+    round_trip([1.0f64, 2.0, 3.0], connection);
+    round_trip([[1u32, 2], [3, 4]], connection);
+    assert_eq!(
+        [1.0f64, 2.0, 3.0].encode().unwrap(),
+        (1.0f64, 2.0f64, 3.0f64).encode().unwrap()
+    );
+}
+
+#[test]
+fn arrays_of_the_wrong_length_are_errors() {
+    let connection = &common::connection();
+    for bytes in [
+        (1.0f64, 2.0f64).encode().unwrap(),
+        (1.0f64, 2.0f64, 3.0f64, 4.0f64).encode().unwrap(),
+        Vec::new(),
+    ]
+    .iter()
+    {
+        match codec_error(<[f64; 3]>::decode(bytes, connection).unwrap_err()) {
+            CodecError::MismatchedTupleLength {
+                actual, expected, ..
+            } => {
+                assert_eq!(expected, 3);
+                assert_ne!(actual, 3);
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+}
+
+#[test]
+fn maps_and_sets() {
+    let connection = &common::connection();
+    let entries = vec![(1u32, "one".to_string()), (2, "two".to_string())];
+    let btree: BTreeMap<_, _> = entries.iter().cloned().collect();
+    let hash: HashMap<_, _> = entries.into_iter().collect();
+    round_trip(btree, connection);
+    round_trip(hash, connection);
+    round_trip((0..5i64).collect::<BTreeSet<_>>(), connection);
+    round_trip((0..5i64).collect::<HashSet<_>>(), connection);
+    round_trip(BTreeMap::<String, Vec<f64>>::new(), connection);
+}
+
+#[test]
+fn malformed_input_is_an_error() {
+    let connection = &common::connection();
+    let list = vec![1u32, 2, 3].encode().unwrap();
+    let map = vec![(1u32, 2u32)]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>()
+        .encode()
+        .unwrap();
+    let garbage = vec![0xff; 4];
+
+    let malformed = |error: Error| match codec_error(error) {
+        CodecError::Malformed { .. } => {}
+        error => panic!("unexpected error {:?}", error),
+    };
+    malformed(Vec::<u32>::decode(&list[..list.len() - 1], connection).unwrap_err());
+    malformed(BTreeMap::<u32, u32>::decode(&map[..map.len() - 1], connection).unwrap_err());
+    malformed(<(u32, u32)>::decode(&garbage, connection).unwrap_err());
+    malformed(Vec::<u32>::decode(&garbage, connection).unwrap_err());
+    malformed(u64::decode(&garbage, connection).unwrap_err());
+    malformed(f64::decode(&[0; 4], connection).unwrap_err());
+    malformed(i32::decode(&[1, 0], connection).unwrap_err());
+    malformed(String::decode(&[5, b'a'], connection).unwrap_err());
+
+    match codec_error(u8::decode(&300u32.encode().unwrap(), connection).unwrap_err()) {
+        CodecError::ValueOutOfRange { value, .. } => assert_eq!(value, 300),
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+fn procedure_error(error: Error) -> ProcedureCodecError {
+    match error.downcast::<ProcedureCodecError>() {
+        Ok(error) => error,
+        Err(error) => panic!("expected a procedure codec error, got {}", error),
+    }
+}
+
+#[test]
+fn malformed_return_values_name_the_procedure() {
+    let (connection, _updates) = common::serving(|call| match call.get_procedure() {
+        // A double is 8 bytes.
+        "get_UT" => vec![0x01, 0x02],
+        "Vessel_get_Situation" => 99i64.encode().unwrap(),
+        procedure => panic!("unexpected call to {}", procedure),
+    });
+
+    let error = procedure_error(SpaceCenter::new(&connection).ut().unwrap_err());
+    assert_eq!(error.service, "SpaceCenter");
+    assert_eq!(error.procedure, "get_UT");
+    assert_eq!(error.value, ProcedureValue::ReturnValue);
+    assert_eq!(error.type_name, "f64");
+    assert!(error
+        .to_string()
+        .starts_with("Failed to decode the return value of SpaceCenter.get_UT as f64: "));
+
+    let vessel = Vessel::new(&connection, 7);
+    let error = procedure_error(vessel.situation().unwrap_err());
+    assert_eq!(error.service, "SpaceCenter");
+    assert_eq!(error.procedure, "Vessel_get_Situation");
+    assert_eq!(error.value, ProcedureValue::ReturnValue);
+    assert!(error.type_name.ends_with("VesselSituation"));
+    match error.error().downcast_ref::<CodecError>() {
+        Some(CodecError::InvalidEnumValue { value: 99, .. }) => {}
+        other => panic!("expected an invalid enum value, got {:?}", other),
+    }
+}
+
+/// A value that always fails to encode.
+struct Unencodable;
+
+impl Encode for Unencodable {
+    fn encode(&self) -> CodecResult<Vec<u8>> {
+        Err(Error::from(CodecError::Malformed {
+            type_name: "Unencodable",
+            reason: "always fails".to_string(),
+        }))
+    }
+}
+
+#[test]
+fn bad_arguments_name_the_procedure_and_position() {
+    assert_eq!(
+        encode_argument(&2.5, "SpaceCenter", "WarpTo", 0).unwrap(),
+        2.5.encode().unwrap()
+    );
+
+    let error =
+        procedure_error(encode_argument(&Unencodable, "SpaceCenter", "WarpTo", 2).unwrap_err());
+    assert_eq!(error.service, "SpaceCenter");
+    assert_eq!(error.procedure, "WarpTo");
+    assert_eq!(error.value, ProcedureValue::Argument(2));
+    assert!(error.type_name.ends_with("Unencodable"));
+    assert!(matches!(
+        error.error().downcast_ref::<CodecError>(),
+        Some(CodecError::Malformed { .. })
+    ));
+    let message = error.to_string();
+    assert!(message.starts_with("Failed to encode argument 2 of SpaceCenter.WarpTo as "));
+    assert!(message.ends_with("always fails"), "{}", message);
+}
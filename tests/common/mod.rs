@@ -0,0 +1,141 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use krpc_bindings::client::schema::{
+    self, ConnectionRequest, ConnectionResponse, ProcedureResult, Request, Response, StreamResult,
+    StreamUpdate,
+};
+use krpc_bindings::client::{Connection, ProcedureCall};
+
+use protobuf::{CodedInputStream, CodedOutputStream, Message};
+
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// The last client identifier given out, so that each connection has its own.
+static CLIENT: AtomicU8 = AtomicU8::new(0);
+
+/// Connects to a stand-in for the kRPC server on a local port, which accepts the connection
+/// handshakes and nothing else. It is enough to decode values, which need a `Connection` for
+/// remote objects, without a running game.
+pub fn connection() -> Connection {
+    let rpc = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stream = TcpListener::bind("127.0.0.1:0").unwrap();
+    let ports = (
+        rpc.local_addr().unwrap().port(),
+        stream.local_addr().unwrap().port(),
+    );
+    thread::spawn(move || accept(rpc));
+    thread::spawn(move || accept(stream));
+    Connection::connect_localhost_with_ports("test", ports.0, ports.1).unwrap()
+}
+
+/// Connects to a stand-in for the kRPC server that answers each procedure call with the value
+/// returned by `respond`. The stream procedures of the `KRPC` service are answered by the
+/// stand-in itself, with stream ids counting up from 1.
+///
+/// Also returns the server's end of the stream connection, for `send_stream_update`.
+pub fn serving<F>(respond: F) -> (Connection, TcpStream)
+where
+    F: FnMut(&ProcedureCall) -> Vec<u8> + Send + 'static,
+{
+    let rpc = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stream = TcpListener::bind("127.0.0.1:0").unwrap();
+    let ports = (
+        rpc.local_addr().unwrap().port(),
+        stream.local_addr().unwrap().port(),
+    );
+    thread::spawn(move || serve(handshake(rpc), respond));
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(handshake(stream)).unwrap());
+    let connection = Connection::connect_localhost_with_ports("test", ports.0, ports.1).unwrap();
+    (connection, receiver.recv().unwrap())
+}
+
+/// Sends a new value for the stream with the given id, as the server does once per tick.
+pub fn send_stream_update(socket: &mut TcpStream, id: u64, value: Vec<u8>) {
+    let mut result = ProcedureResult::new();
+    result.set_value(value);
+    let mut stream_result = StreamResult::new();
+    stream_result.set_id(id);
+    stream_result.set_result(result);
+    let mut update = StreamUpdate::new();
+    update.mut_results().push(stream_result);
+    send(socket, &update);
+}
+
+/// Checks that `actual` is within `tolerance` of `expected`, relative to the magnitude of
+/// `expected` when it is larger than 1.
+pub fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance * expected.abs().max(1.0),
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+/// Answers a connection request, then holds the socket open until the client closes it.
+fn accept(listener: TcpListener) {
+    drain(handshake(listener));
+}
+
+/// Accepts a connection and answers its connection request.
+fn handshake(listener: TcpListener) -> TcpStream {
+    let (mut socket, _) = listener.accept().unwrap();
+    let _: ConnectionRequest = CodedInputStream::new(&mut socket).read_message().unwrap();
+
+    let mut response = ConnectionResponse::new();
+    response.set_client_identifier(vec![CLIENT.fetch_add(1, Ordering::SeqCst) + 1; 16]);
+    send(&mut socket, &response);
+    socket
+}
+
+/// Answers requests until the client closes the connection.
+fn serve<F>(mut socket: TcpStream, mut respond: F)
+where
+    F: FnMut(&ProcedureCall) -> Vec<u8>,
+{
+    let mut streams = 0;
+    loop {
+        let request: Request = match CodedInputStream::new(&mut socket).read_message() {
+            Ok(request) => request,
+            Err(_) => return,
+        };
+        let mut response = Response::new();
+        for call in request.get_calls() {
+            let value = match (call.get_service(), call.get_procedure()) {
+                ("KRPC", "AddStream") => {
+                    streams += 1;
+                    let mut stream = schema::Stream::new();
+                    stream.set_id(streams);
+                    stream.write_to_bytes().unwrap()
+                }
+                ("KRPC", "StartStream") | ("KRPC", "RemoveStream") => Vec::new(),
+                _ => respond(call),
+            };
+            let mut result = ProcedureResult::new();
+            result.set_value(value);
+            response.mut_results().push(result);
+        }
+        send(&mut socket, &response);
+    }
+}
+
+fn send<M: Message>(socket: &mut TcpStream, message: &M) {
+    let mut cos = CodedOutputStream::new(socket);
+    cos.write_message_no_tag(message).unwrap();
+    cos.flush().unwrap();
+}
+
+fn drain(mut socket: TcpStream) {
+    let mut buffer = [0; 256];
+    while let Ok(read) = socket.read(&mut buffer) {
+        if read == 0 {
+            break;
+        }
+    }
+}
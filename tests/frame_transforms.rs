@@ -0,0 +1,146 @@
+//! Checks `FrameTransform` against the transforms in `tests/data/frame_transforms.txt`.
+//!
+//! That file is in the format written by `examples/record_frames.rs`, but it was generated from
+//! an analytic rigid-body model rather than recorded from the server, so these tests check the
+//! quaternion code against independent matrix arithmetic, not against the game. Agreement with
+//! the server still needs a recording from a live session.
+use krpc_bindings::spacecenter::FrameTransform;
+use krpc_bindings::{Quaternion, Vector3};
+
+use std::collections::HashMap;
+
+const MODEL: &str = include_str!("data/frame_transforms.txt");
+
+/// Allowed error, relative to the magnitude of the expected value, or in radians for rotations.
+/// The model was evaluated in double precision, so this leaves room for rounding only.
+const TOLERANCE: f64 = 1e-6;
+
+struct Line<'s> {
+    kind: &'s str,
+    frames: Vec<&'s str>,
+    numbers: Vec<f64>,
+}
+
+fn lines() -> impl Iterator<Item = Line<'static>> {
+    MODEL
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut words = line.split_whitespace();
+            let kind = words.next().unwrap();
+            let (frames, numbers): (Vec<_>, Vec<_>) =
+                words.partition(|word| word.parse::<f64>().is_err());
+            Line {
+                kind,
+                frames,
+                numbers: numbers.iter().map(|n| n.parse().unwrap()).collect(),
+            }
+        })
+}
+
+fn vector(numbers: &[f64]) -> Vector3 {
+    Vector3::new(numbers[0], numbers[1], numbers[2])
+}
+
+fn quaternion(numbers: &[f64]) -> Quaternion {
+    Quaternion::new(numbers[0], numbers[1], numbers[2], numbers[3])
+}
+
+/// The transforms from each recorded frame to the root frame, `R`.
+fn samples() -> HashMap<&'static str, FrameTransform> {
+    let mut samples: HashMap<_, _> = lines()
+        .filter(|line| line.kind == "sample")
+        .map(|line| {
+            let n = &line.numbers;
+            let transform = FrameTransform::from_samples(
+                vector(&n[0..3]),
+                quaternion(&n[3..7]),
+                vector(&n[7..10]),
+                [vector(&n[10..13]), vector(&n[13..16]), vector(&n[16..19])],
+            );
+            (line.frames[0], transform)
+        })
+        .collect();
+    samples.insert("R", FrameTransform::IDENTITY);
+    samples
+}
+
+fn transform(samples: &HashMap<&str, FrameTransform>, from: &str, to: &str) -> FrameTransform {
+    samples[from].then(&samples[to].inverse())
+}
+
+fn assert_close(actual: Vector3, expected: Vector3, what: &str) {
+    let error = (actual - expected).magnitude();
+    assert!(
+        error <= TOLERANCE * expected.magnitude().max(1.0),
+        "{}: expected {:?}, got {:?}",
+        what,
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn agrees_with_rigid_body_model() {
+    let samples = samples();
+    let mut checked = 0;
+
+    for line in lines().filter(|line| line.kind != "sample") {
+        let t = transform(&samples, line.frames[0], line.frames[1]);
+        let n = &line.numbers;
+        let what = format!("{} {:?} {:?}", line.kind, line.frames, n);
+        match line.kind {
+            "position" => assert_close(
+                t.transform_position(vector(&n[0..3])),
+                vector(&n[3..6]),
+                &what,
+            ),
+            "direction" => assert_close(
+                t.transform_direction(vector(&n[0..3])),
+                vector(&n[3..6]),
+                &what,
+            ),
+            "rotation" => {
+                let actual = t.transform_rotation(quaternion(&n[0..4]));
+                let expected = quaternion(&n[4..8]);
+                assert!(
+                    actual.angle_between(expected) <= TOLERANCE,
+                    "{}: expected {:?}, got {:?}",
+                    what,
+                    expected,
+                    actual
+                );
+            }
+            "velocity" => assert_close(
+                t.transform_velocity(vector(&n[0..3]), vector(&n[3..6])),
+                vector(&n[6..9]),
+                &what,
+            ),
+            kind => panic!("unknown line kind {}", kind),
+        }
+        checked += 1;
+    }
+
+    assert!(checked > 0, "the model has no answers to check");
+}
+
+#[test]
+fn inverse_round_trips() {
+    let samples = samples();
+    let t = transform(&samples, "B", "A");
+    let round_trip = t.then(&t.inverse());
+    let position = Vector3::new(12.0, -5.0, 3.5);
+    let velocity = Vector3::new(-7.0, 2.0, 40.0);
+
+    assert_close(
+        round_trip.transform_position(position),
+        position,
+        "position",
+    );
+    assert_close(
+        round_trip.transform_velocity(position, velocity),
+        velocity,
+        "velocity",
+    );
+}
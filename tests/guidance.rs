@@ -0,0 +1,140 @@
+//! Checks the calculations that the flight scripts make without a server.
+use krpc_bindings::guidance::{
+    approach_velocity, burn_time, suicide_burn_start, translation_controls, GravityTurn,
+    STANDARD_GRAVITY,
+};
+use krpc_bindings::Vector3;
+
+#[test]
+fn burn_time_follows_the_rocket_equation() {
+    // 10 t, with 100 kN of thrust at 300 s, for 500 m/s.
+    let (mass, thrust, isp) = (10_000.0, 100_000.0, 300.0);
+    let time = burn_time(500.0, thrust, isp, mass);
+    let burned = thrust / (isp * STANDARD_GRAVITY) * time;
+    let delta_v = isp * STANDARD_GRAVITY * (mass / (mass - burned)).ln();
+    assert!((delta_v - 500.0).abs() < 1e-9);
+
+    // Slightly longer than at a constant acceleration, as the vessel gets lighter.
+    assert!(time < 500.0 * mass / thrust);
+    assert_eq!(burn_time(0.0, thrust, isp, mass), 0.0);
+}
+
+#[test]
+fn gravity_turn_profile() {
+    let turn = GravityTurn {
+        start_altitude: 1000.0,
+        end_altitude: 41_000.0,
+        exponent: 0.5,
+        final_pitch: 10.0,
+    };
+    assert_eq!(turn.pitch_at(0.0), 90.0);
+    assert_eq!(turn.pitch_at(1000.0), 90.0);
+    assert_eq!(turn.pitch_at(11_000.0), 90.0 - 80.0 * 0.5);
+    assert_eq!(turn.pitch_at(41_000.0), 10.0);
+    assert_eq!(turn.pitch_at(70_000.0), 10.0);
+
+    let mut previous = 90.0;
+    for altitude in (0..50).map(|i| f64::from(i) * 1000.0) {
+        let pitch = turn.pitch_at(altitude);
+        assert!(pitch <= previous);
+        previous = pitch;
+    }
+}
+
+#[test]
+fn suicide_burn_stops_at_the_ground() {
+    let (gravity, acceleration) = (1.63, 6.0);
+    for &(altitude, vertical_speed) in [(5000.0, -100.0), (12_000.0, 50.0), (800.0, 0.0)].iter() {
+        let start = suicide_burn_start(altitude, vertical_speed, acceleration, gravity).unwrap();
+        assert!(start > 0.0);
+
+        // Fall until the burn starts, then burn at full thrust until the vessel stops.
+        let (mut height, mut speed) = (altitude, vertical_speed);
+        height += speed * start - gravity * start * start / 2.0;
+        speed -= gravity * start;
+        let stop = -speed / (acceleration - gravity);
+        height += speed * stop + (acceleration - gravity) * stop * stop / 2.0;
+        assert!(height.abs() < 1e-6, "stopped {} m above the ground", height);
+    }
+
+    // Too fast to stop, and too little thrust to hover.
+    assert!(suicide_burn_start(100.0, -100.0, acceleration, gravity).unwrap() < 0.0);
+    assert_eq!(suicide_burn_start(1000.0, 0.0, 1.5, gravity), None);
+}
+
+#[test]
+fn approach_holds_at_the_standoff_until_lined_up() {
+    let (standoff, speed) = (20.0, 0.5);
+
+    // Off the axis, it moves towards the axis and the standoff distance, from either side.
+    let inside = approach_velocity(Vector3::new(2.0, 5.0, -1.0), standoff, speed);
+    assert!(inside.x < 0.0 && inside.z > 0.0 && inside.y > 0.0);
+    let beyond = approach_velocity(Vector3::new(2.0, 60.0, -1.0), standoff, speed);
+    assert!(beyond.x < 0.0 && beyond.z > 0.0 && beyond.y < 0.0);
+    let holding = approach_velocity(Vector3::new(2.0, standoff, -1.0), standoff, speed);
+    assert_eq!(holding.y, 0.0);
+
+    // Lined up, it moves in at the approach speed, slowing to half of it at the end.
+    assert_eq!(
+        approach_velocity(Vector3::new(0.1, 60.0, 0.0), standoff, speed).y,
+        -speed
+    );
+    assert_eq!(
+        approach_velocity(Vector3::new(0.1, 1.0, 0.0), standoff, speed).y,
+        -speed / 2.0
+    );
+}
+
+#[test]
+fn approach_only_moves_in_once_lined_up() {
+    // Follow the wanted velocity exactly, from off the axis on either side of the standoff.
+    let (standoff, speed, step) = (20.0, 0.5, 0.1);
+    for &start in [
+        Vector3::new(4.0, 3.0, -2.0),
+        Vector3::new(24.0, 30.0, -18.0),
+    ]
+    .iter()
+    {
+        let beyond = start.y > standoff;
+        let mut position = start;
+        let mut lined_up = false;
+        for _ in 0..10_000 {
+            let velocity = approach_velocity(position, standoff, speed);
+            if Vector3::new(position.x, 0.0, position.z).magnitude() > 0.5 {
+                // Not past the standoff distance until lined up, and no further from the axis.
+                assert!(!lined_up, "moved off the axis at {:?}", position);
+                assert_eq!(position.y > standoff, beyond, "crossed at {:?}", position);
+            } else {
+                lined_up = true;
+                assert!(velocity.y < 0.0);
+            }
+            position += velocity * step;
+            if position.y <= 0.0 {
+                break;
+            }
+        }
+        assert!(lined_up && position.y <= 0.0, "stopped at {:?}", position);
+    }
+}
+
+#[test]
+fn translation_maps_onto_the_rcs_controls() {
+    // The controls are (forward, right, up), and z points out of the bottom of the vessel.
+    assert_eq!(
+        translation_controls(Vector3::new(0.0, 0.5, 0.0)),
+        (0.5, 0.0, 0.0)
+    );
+    assert_eq!(
+        translation_controls(Vector3::new(0.25, 0.0, 0.0)),
+        (0.0, 0.25, 0.0)
+    );
+    assert_eq!(
+        translation_controls(Vector3::new(0.0, 0.0, 0.75)),
+        (0.0, 0.0, -0.75)
+    );
+    assert_eq!(
+        translation_controls(Vector3::new(-3.0, 2.0, -1.5)),
+        (1.0, -1.0, 1.0)
+    );
+    assert_eq!(translation_controls(Vector3::ZERO), (0.0, 0.0, 0.0));
+}
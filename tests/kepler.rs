@@ -0,0 +1,301 @@
+//! Checks `KeplerOrbit` against the closed-form properties of Keplerian orbits.
+mod common;
+
+use common::assert_close;
+use krpc_bindings::kepler::{eccentric_anomaly, KeplerOrbit, OrbitalElements, StateVector};
+use krpc_bindings::Vector3;
+
+use std::f64::consts::PI;
+
+/// The gravitational parameter of Kerbin, in m<sup>3</sup>/s<sup>2</sup>.
+const KERBIN_MU: f64 = 3.5316e12;
+
+fn orbit(semi_major_axis: f64, eccentricity: f64) -> KeplerOrbit {
+    KeplerOrbit::new(
+        OrbitalElements {
+            periapsis: semi_major_axis * (1.0 - eccentricity),
+            eccentricity,
+            inclination: 0.4,
+            longitude_of_ascending_node: 1.1,
+            argument_of_periapsis: 2.3,
+            mean_anomaly_at_epoch: 0.5,
+            epoch: 1000.0,
+        },
+        KERBIN_MU,
+    )
+}
+
+fn assert_same_state(actual: &StateVector, expected: &StateVector, what: &str) {
+    assert!(
+        (actual.position - expected.position).magnitude() <= 1e-9 * expected.position.magnitude()
+            && (actual.velocity - expected.velocity).magnitude()
+                <= 1e-9 * expected.velocity.magnitude(),
+        "{}: expected {:?}, got {:?}",
+        what,
+        expected,
+        actual
+    );
+}
+
+fn specific_energy(orbit: &KeplerOrbit, ut: f64) -> f64 {
+    let state = orbit.state_at(ut);
+    state.velocity.magnitude_squared() / 2.0 - KERBIN_MU / state.position.magnitude()
+}
+
+fn angular_momentum(orbit: &KeplerOrbit, ut: f64) -> Vector3 {
+    let state = orbit.state_at(ut);
+    state.position.cross(state.velocity)
+}
+
+#[test]
+fn low_kerbin_orbit() {
+    let orbit = orbit(700_000.0, 0.0);
+
+    assert_close(orbit.period(), 1958.2, 1e-4);
+    for &ut in [0.0, 500.0, 1234.5, 1e6].iter() {
+        assert_close(orbit.radius_at(ut), 700_000.0, 1e-12);
+        assert_close(orbit.velocity_at(ut).magnitude(), 2246.1, 1e-4);
+    }
+}
+
+#[test]
+fn kepler_equation_is_solved() {
+    for &e in [0.0, 0.1, 0.5, 0.9, 0.99, 0.999].iter() {
+        for i in 0..64 {
+            let m = 2.0 * PI * f64::from(i) / 64.0;
+            let anomaly = eccentric_anomaly(m, e);
+            assert_close(anomaly - e * anomaly.sin(), m, 1e-12);
+        }
+    }
+    for &e in [1.001, 1.5, 3.0, 20.0].iter() {
+        for &m in [-1000.0, -10.0, -0.1, 0.0, 0.001, 2.0, 50.0, 1e5].iter() {
+            let anomaly = eccentric_anomaly(m, e);
+            assert_close(e * anomaly.sinh() - anomaly, m, 1e-12);
+        }
+    }
+    for &m in [-1e6, -3.0, -0.01, 0.0, 0.5, 40.0, 1e9].iter() {
+        let anomaly = eccentric_anomaly(m, 1.0);
+        assert_close(anomaly + anomaly.powi(3) / 3.0, m, 1e-12);
+    }
+}
+
+#[test]
+fn elliptical_orbit_conserves_energy_and_angular_momentum() {
+    let orbit = orbit(2_000_000.0, 0.6);
+    let energy = -KERBIN_MU / (2.0 * 2_000_000.0);
+    let momentum = angular_momentum(&orbit, 1000.0);
+
+    for i in 0..50 {
+        let ut = 1000.0 + orbit.period() * f64::from(i) / 37.0;
+        assert_close(specific_energy(&orbit, ut), energy, 1e-9);
+        assert!(
+            (angular_momentum(&orbit, ut) - momentum).magnitude() <= 1e-9 * momentum.magnitude()
+        );
+    }
+}
+
+#[test]
+fn apsides_and_period() {
+    let orbit = orbit(2_000_000.0, 0.6);
+    let epoch = orbit.elements.epoch;
+    let time_to_periapsis = (2.0 * PI - orbit.elements.mean_anomaly_at_epoch) / orbit.mean_motion();
+
+    assert_close(orbit.radius_at(epoch + time_to_periapsis), 800_000.0, 1e-9);
+    assert_close(
+        orbit.radius_at(epoch + time_to_periapsis + orbit.period() / 2.0),
+        3_200_000.0,
+        1e-9,
+    );
+    let start = orbit.position_at(epoch);
+    assert!((orbit.position_at(epoch + 3.0 * orbit.period()) - start).magnitude() <= 1e-6);
+}
+
+#[test]
+fn velocity_is_derivative_of_position() {
+    for orbit in [orbit(2_000_000.0, 0.6), orbit(-1_500_000.0, 1.8)].iter() {
+        for &ut in [1000.0, 1500.0, 4321.0].iter() {
+            let dt = 1e-3;
+            let derivative = (orbit.position_at(ut + dt) - orbit.position_at(ut - dt)) / (2.0 * dt);
+            let velocity = orbit.velocity_at(ut);
+            assert!(
+                (derivative - velocity).magnitude() <= 1e-6 * velocity.magnitude(),
+                "expected {:?}, got {:?}",
+                velocity,
+                derivative
+            );
+        }
+    }
+}
+
+#[test]
+fn hyperbolic_orbit() {
+    let orbit = orbit(-1_500_000.0, 1.8);
+
+    assert!(orbit.is_hyperbolic());
+    assert_eq!(orbit.period(), f64::INFINITY);
+    assert_close(orbit.periapsis(), 1_200_000.0, 1e-12);
+    assert_close(
+        specific_energy(&orbit, 2000.0),
+        KERBIN_MU / (2.0 * 1_500_000.0),
+        1e-9,
+    );
+
+    let time_to_periapsis = -orbit.elements.mean_anomaly_at_epoch / orbit.mean_motion();
+    let at_periapsis = orbit.elements.epoch + time_to_periapsis;
+    assert_close(orbit.radius_at(at_periapsis), 1_200_000.0, 1e-9);
+    assert!(orbit.true_anomaly_at(at_periapsis - 100.0) < 0.0);
+    assert!(orbit.true_anomaly_at(at_periapsis + 100.0) > 0.0);
+}
+
+#[test]
+fn orientation_follows_the_reference_plane() {
+    let orbit = orbit(2_000_000.0, 0.6);
+    let elements = orbit.elements;
+
+    // Reference frames are left-handed, so the angular momentum of a prograde orbit computed
+    // with the usual cross product points south.
+    let normal = -angular_momentum(&orbit, 1000.0);
+    assert_close(
+        normal.angle_between(orbit.reference_normal),
+        elements.inclination,
+        1e-12,
+    );
+
+    // The ascending node is where the argument of latitude is zero, at the longitude of the
+    // ascending node from the reference direction.
+    let node = orbit.position_at_true_anomaly(-elements.argument_of_periapsis);
+    assert_close(node.y, 0.0, 1e-9);
+    let (sin, cos) = elements.longitude_of_ascending_node.sin_cos();
+    let expected = Vector3::new(cos, 0.0, sin);
+    assert!((node.normalize() - expected).magnitude() <= 1e-12);
+    assert!(
+        orbit
+            .position_at_true_anomaly(PI / 2.0 - elements.argument_of_periapsis)
+            .y
+            > 0.0
+    );
+}
+
+#[test]
+fn elements_round_trip_through_state_vectors() {
+    for orbit in [
+        orbit(2_000_000.0, 0.6),
+        orbit(-1_500_000.0, 1.8),
+        orbit(700_000.0, 0.05),
+    ]
+    .iter()
+    {
+        let state = orbit.state_at(1500.0);
+        let recovered = KeplerOrbit::from_state_vector(&state, 1500.0, KERBIN_MU).unwrap();
+        let (expected, actual) = (orbit.elements, recovered.elements);
+
+        assert_close(actual.periapsis, expected.periapsis, 1e-9);
+        assert_close(actual.eccentricity, expected.eccentricity, 1e-9);
+        assert_close(actual.inclination, expected.inclination, 1e-9);
+        assert_close(
+            actual.longitude_of_ascending_node,
+            expected.longitude_of_ascending_node,
+            1e-9,
+        );
+        assert_close(
+            actual.argument_of_periapsis,
+            expected.argument_of_periapsis,
+            1e-9,
+        );
+        assert_eq!(actual.epoch, 1500.0);
+        assert_same_state(
+            &recovered.state_at(4000.0),
+            &orbit.state_at(4000.0),
+            "later",
+        );
+    }
+}
+
+#[test]
+fn state_vectors_round_trip_in_edge_cases() {
+    let radius = 800_000.0;
+    let circular = (KERBIN_MU / radius).sqrt();
+    let escape = (2.0 * KERBIN_MU / radius).sqrt();
+    let position = Vector3::new(radius, 0.0, 0.0);
+    let tilted = Vector3::new(0.3, 0.4, 0.8).normalize();
+    let cases = [
+        ("equatorial", Vector3::new(0.0, 0.0, 1.2 * circular)),
+        ("retrograde", Vector3::new(0.0, 0.0, -1.2 * circular)),
+        ("circular", Vector3::new(0.0, 0.6, 0.8) * circular),
+        ("circular equatorial", Vector3::new(0.0, 0.0, circular)),
+        ("polar", Vector3::new(0.0, 1.1 * circular, 0.0)),
+        ("parabolic", tilted * escape),
+        ("hyperbolic", tilted * 1.5 * escape),
+    ];
+
+    for &(what, velocity) in cases.iter() {
+        let state = StateVector { position, velocity };
+        let orbit = KeplerOrbit::from_state_vector(&state, 1000.0, KERBIN_MU).unwrap();
+        assert_same_state(&orbit.state_at(1000.0), &state, what);
+        assert_same_state(
+            &orbit.elements.state_vector_at(1000.0, KERBIN_MU),
+            &state,
+            what,
+        );
+
+        // Starting again from a later point gives the same orbit.
+        let later = orbit.state_at(1600.0);
+        let again = KeplerOrbit::from_state_vector(&later, 1600.0, KERBIN_MU).unwrap();
+        assert_same_state(&again.state_at(2000.0), &orbit.state_at(2000.0), what);
+
+        let dt = 1e-3;
+        let derivative =
+            (orbit.position_at(1600.0 + dt) - orbit.position_at(1600.0 - dt)) / (2.0 * dt);
+        assert!((derivative - later.velocity).magnitude() <= 1e-6 * later.velocity.magnitude());
+    }
+
+    let elements = |velocity| {
+        let state = StateVector { position, velocity };
+        OrbitalElements::from_state_vector(&state, 0.0, KERBIN_MU).unwrap()
+    };
+    let equatorial = elements(cases[0].1);
+    assert_eq!(equatorial.inclination, 0.0);
+    assert_eq!(equatorial.longitude_of_ascending_node, 0.0);
+    assert_close(elements(cases[1].1).inclination, PI, 1e-12);
+    assert_eq!(elements(cases[2].1).argument_of_periapsis, 0.0);
+    assert_close(elements(cases[4].1).inclination, PI / 2.0, 1e-12);
+
+    let parabolic = KeplerOrbit::new(elements(cases[5].1), KERBIN_MU);
+    assert!(parabolic.is_parabolic());
+    assert_eq!(parabolic.semi_major_axis(), f64::INFINITY);
+    assert_eq!(parabolic.period(), f64::INFINITY);
+    assert_close(specific_energy(&parabolic, 5000.0), 0.0, 1e-9);
+}
+
+#[test]
+fn radial_trajectories_have_no_elements() {
+    let state = StateVector {
+        position: Vector3::new(0.0, 700_000.0, 0.0),
+        velocity: Vector3::new(0.0, -300.0, 0.0),
+    };
+    assert_eq!(KeplerOrbit::from_state_vector(&state, 0.0, KERBIN_MU), None);
+}
+
+#[test]
+fn burn_changes_the_orbit() {
+    let orbit = KeplerOrbit::from_state_vector(
+        &StateVector {
+            position: Vector3::new(700_000.0, 0.0, 0.0),
+            velocity: Vector3::new(0.0, 0.0, (KERBIN_MU / 700_000.0).sqrt()),
+        },
+        0.0,
+        KERBIN_MU,
+    )
+    .unwrap();
+
+    // A prograde burn raises the opposite side of the orbit, leaving the periapsis here.
+    let state = orbit.state_at(100.0);
+    let burned = StateVector {
+        velocity: state.velocity * 1.1,
+        ..state
+    };
+    let after = orbit.with_state_vector(&burned, 100.0).unwrap();
+    assert_close(after.periapsis(), 700_000.0, 1e-9);
+    assert_close(after.apoapsis(), 700_000.0 * 1.21 / (2.0 - 1.21), 1e-9);
+    assert_same_state(&after.state_at(100.0), &burned, "after the burn");
+}
@@ -0,0 +1,248 @@
+//! Checks the planned maneuver nodes by propagating the orbits they lead to.
+mod common;
+
+use common::assert_close;
+use krpc_bindings::kepler::maneuver::{
+    change_apoapsis, change_periapsis, circularize_at_apoapsis, circularize_at_periapsis,
+    hohmann_transfer, match_planes_at, return_from_moon,
+};
+use krpc_bindings::kepler::{KeplerOrbit, ManeuverNode, OrbitalElements, StateVector};
+use krpc_bindings::Vector3;
+
+/// The gravitational parameters of Kerbin and the Mun, in m<sup>3</sup>/s<sup>2</sup>.
+const KERBIN_MU: f64 = 3.5316e12;
+const MUN_MU: f64 = 6.5138398e10;
+
+fn kerbin_orbit(
+    periapsis: f64,
+    eccentricity: f64,
+    inclination: f64,
+    mean_anomaly: f64,
+) -> KeplerOrbit {
+    KeplerOrbit::new(
+        OrbitalElements {
+            periapsis,
+            eccentricity,
+            inclination,
+            longitude_of_ascending_node: 0.8,
+            argument_of_periapsis: 2.1,
+            mean_anomaly_at_epoch: mean_anomaly,
+            epoch: 0.0,
+        },
+        KERBIN_MU,
+    )
+}
+
+fn orbit_normal(orbit: &KeplerOrbit) -> Vector3 {
+    let state = orbit.state_at(0.0);
+    state.velocity.cross(state.position).normalize()
+}
+
+#[test]
+fn node_components_round_trip() {
+    let orbit = kerbin_orbit(700_000.0, 0.1, 0.3, 1.0);
+    let node = ManeuverNode {
+        ut: 250.0,
+        prograde: 120.0,
+        normal: -35.0,
+        radial: 12.5,
+    };
+    let velocity = orbit.velocity_at(node.ut) + orbit.maneuver_delta_v(&node);
+    let planned = orbit.maneuver_to_velocity(node.ut, velocity);
+
+    assert_close(planned.prograde, node.prograde, 1e-9);
+    assert_close(planned.normal, node.normal, 1e-9);
+    assert_close(planned.radial, node.radial, 1e-9);
+    assert_close(
+        node.delta_v(),
+        orbit.maneuver_delta_v(&node).magnitude(),
+        1e-12,
+    );
+}
+
+#[test]
+fn node_directions() {
+    // An equatorial prograde orbit, at (r, 0, 0) and moving along +z at `ut`.
+    let orbit = KeplerOrbit::from_state_vector(
+        &StateVector {
+            position: Vector3::new(700_000.0, 0.0, 0.0),
+            velocity: Vector3::new(0.0, 0.0, 2246.0),
+        },
+        0.0,
+        KERBIN_MU,
+    )
+    .unwrap();
+    let delta_v = |prograde, normal, radial| {
+        orbit.maneuver_delta_v(&ManeuverNode {
+            ut: 0.0,
+            prograde,
+            normal,
+            radial,
+        })
+    };
+
+    assert!((delta_v(1.0, 0.0, 0.0) - Vector3::new(0.0, 0.0, 1.0)).magnitude() < 1e-12);
+    assert!((delta_v(0.0, 1.0, 0.0) - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+    assert!((delta_v(0.0, 0.0, 1.0) - Vector3::new(1.0, 0.0, 0.0)).magnitude() < 1e-12);
+}
+
+#[test]
+fn circularize() {
+    let orbit = kerbin_orbit(700_000.0, 0.3, 0.4, 1.0);
+
+    let node = circularize_at_apoapsis(&orbit, 100.0).unwrap();
+    assert!(node.ut >= 100.0 && node.ut < 100.0 + orbit.period());
+    let after = orbit.after_maneuver(&node).unwrap();
+    assert!(after.elements.eccentricity < 1e-9);
+    assert_close(after.semi_major_axis(), orbit.apoapsis(), 1e-9);
+
+    let node = circularize_at_periapsis(&orbit, 100.0);
+    let after = orbit.after_maneuver(&node).unwrap();
+    assert!(node.prograde < 0.0);
+    assert!(after.elements.eccentricity < 1e-9);
+    assert_close(after.semi_major_axis(), orbit.periapsis(), 1e-9);
+
+    let escaping = kerbin_orbit(700_000.0, 1.4, 0.4, 1.0);
+    assert_eq!(circularize_at_apoapsis(&escaping, 100.0), None);
+}
+
+#[test]
+fn change_apsides() {
+    let orbit = kerbin_orbit(700_000.0, 0.2, 0.4, 1.0);
+
+    let node = change_apoapsis(&orbit, 0.0, 5_000_000.0);
+    let after = orbit.after_maneuver(&node).unwrap();
+    assert_close(after.periapsis(), 700_000.0, 1e-9);
+    assert_close(after.apoapsis(), 5_000_000.0, 1e-9);
+    assert_eq!((node.normal, node.radial), (0.0, 0.0));
+
+    let node = change_periapsis(&orbit, 0.0, 650_000.0).unwrap();
+    let after = orbit.after_maneuver(&node).unwrap();
+    assert_close(after.apoapsis(), orbit.apoapsis(), 1e-9);
+    assert_close(after.periapsis(), 650_000.0, 1e-9);
+    assert!(node.prograde < 0.0);
+}
+
+#[test]
+fn hohmann_transfer_meets_the_target() {
+    for &(from, to) in [(700_000.0, 2_500_000.0), (3_000_000.0, 800_000.0)].iter() {
+        let orbit = kerbin_orbit(from, 0.0, 0.2, 0.3);
+        let target = kerbin_orbit(to, 0.0, 0.2, 4.0);
+        let [departure, arrival] = hohmann_transfer(&orbit, &target, 1000.0).unwrap();
+
+        assert!(departure.ut >= 1000.0 - 1e-6);
+        let transfer = orbit.after_maneuver(&departure).unwrap();
+        let meeting = transfer.position_at(arrival.ut);
+        assert!(
+            (meeting - target.position_at(arrival.ut)).magnitude() <= 1e-3 * to,
+            "missed the target by {}",
+            (meeting - target.position_at(arrival.ut)).magnitude()
+        );
+
+        let after = transfer.after_maneuver(&arrival).unwrap();
+        assert!(after.elements.eccentricity < 1e-9);
+        assert_close(after.semi_major_axis(), to, 1e-9);
+    }
+}
+
+#[test]
+fn hohmann_transfer_needs_a_transfer_window() {
+    // The same orbit, with the target ahead, never lines up for a transfer.
+    let orbit = kerbin_orbit(700_000.0, 0.0, 0.2, 0.3);
+    let target = kerbin_orbit(700_000.0, 0.0, 0.2, 0.5);
+    assert_eq!(hohmann_transfer(&orbit, &target, 1000.0), None);
+    assert_eq!(hohmann_transfer(&target, &orbit, 1000.0), None);
+
+    // Nearly the same orbit lines up only after thousands of orbits.
+    let target = kerbin_orbit(700_100.0, 0.0, 0.2, 0.5);
+    assert_eq!(hohmann_transfer(&orbit, &target, 1000.0), None);
+
+    // A close orbit still has a window within a few tens of orbits.
+    let target = kerbin_orbit(720_000.0, 0.0, 0.2, 0.5);
+    let [departure, arrival] = hohmann_transfer(&orbit, &target, 1000.0).unwrap();
+    assert!(departure.ut >= 1000.0 - 1e-6);
+    assert!(departure.ut <= 1000.0 + 100.0 * orbit.period());
+    assert!(arrival.ut > departure.ut && arrival.delta_v().is_finite());
+}
+
+#[test]
+fn match_planes() {
+    let orbit = kerbin_orbit(700_000.0, 0.1, 0.3, 1.0);
+    let target = KeplerOrbit {
+        elements: OrbitalElements {
+            inclination: 0.7,
+            longitude_of_ascending_node: 2.0,
+            ..orbit.elements
+        },
+        ..orbit
+    };
+
+    // The line of nodes is along the intersection of the planes, perpendicular to both
+    // normals.
+    let line = orbit_normal(&orbit).cross(orbit_normal(&target));
+    let periapsis = orbit.position_at_true_anomaly(0.0);
+    for &direction in [line, -line].iter() {
+        let axis = -orbit_normal(&orbit);
+        let true_anomaly = axis
+            .dot(periapsis.cross(direction))
+            .atan2(periapsis.dot(direction));
+        let node = match_planes_at(&orbit, &target, true_anomaly, 50.0);
+        let after = orbit.after_maneuver(&node).unwrap();
+
+        assert!(node.ut >= 50.0);
+        assert!(orbit_normal(&after).angle_between(orbit_normal(&target)) < 1e-9);
+        assert_close(after.semi_major_axis(), orbit.semi_major_axis(), 1e-9);
+    }
+}
+
+#[test]
+fn return_from_the_mun() {
+    // The Mun, on a circular orbit around Kerbin, and a vessel in a low orbit around it, in
+    // axes with the same orientation.
+    let mun = KeplerOrbit::new(
+        OrbitalElements {
+            periapsis: 12_000_000.0,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            mean_anomaly_at_epoch: 1.7,
+            epoch: 0.0,
+        },
+        KERBIN_MU,
+    );
+    let orbit = KeplerOrbit::new(
+        OrbitalElements {
+            periapsis: 230_000.0,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            mean_anomaly_at_epoch: 0.4,
+            epoch: 0.0,
+        },
+        MUN_MU,
+    );
+
+    let node = return_from_moon(&orbit, &mun, 630_000.0, 500.0);
+    assert!(node.ut >= 500.0 && node.ut < 500.0 + orbit.period());
+    let escape = orbit.after_maneuver(&node).unwrap();
+    assert!(escape.is_hyperbolic());
+
+    // Far from the Mun, the vessel moves against the Mun's velocity at the time of the burn,
+    // which leaves it on an orbit of Kerbin that falls to the wanted periapsis.
+    let leaving = escape.velocity_at(node.ut + 1e7);
+    let mun_state = mun.state_at(node.ut);
+    assert!(leaving.angle_between(-mun_state.velocity) < 1e-3);
+    let home = KeplerOrbit::from_state_vector(
+        &StateVector {
+            position: mun_state.position,
+            velocity: mun_state.velocity + leaving,
+        },
+        node.ut,
+        KERBIN_MU,
+    )
+    .unwrap();
+    assert_close(home.periapsis(), 630_000.0, 1e-2);
+    assert_close(home.apoapsis(), 12_000_000.0, 1e-3);
+}
@@ -0,0 +1,189 @@
+//! Checks the vector and quaternion operations against rotations worked out by hand.
+mod common;
+
+use common::assert_close;
+use krpc_bindings::{Quaternion, Vector3};
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const X: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+const Y: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+const Z: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+
+fn assert_same_vector(actual: Vector3, expected: Vector3) {
+    assert!(
+        (actual - expected).magnitude() <= 1e-12,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+/// Checks that the two quaternions are the same rotation, allowing for `q` and `-q`.
+fn assert_same_rotation(actual: Quaternion, expected: Quaternion) {
+    assert!(
+        (actual.dot(expected).abs() - 1.0).abs() <= 1e-12,
+        "expected {:?}, got {:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn rotate_about_the_axes() {
+    let quarter = |axis| Quaternion::from_axis_angle(axis, FRAC_PI_2);
+    assert_same_vector(quarter(Z).rotate(X), Y);
+    assert_same_vector(quarter(X).rotate(Y), Z);
+    assert_same_vector(quarter(Y).rotate(Z), X);
+    assert_same_vector(quarter(Z) * X, Y);
+
+    // `a * b` applies `b` first.
+    assert_same_vector((quarter(X) * quarter(Z)).rotate(X), Z);
+    assert_same_vector(
+        Quaternion::IDENTITY.rotate(Vector3::new(1.0, 2.0, 3.0)),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+
+    // The axis does not need to be normalized, and the length of the vector is kept.
+    let v = Vector3::new(3.0, -4.0, 12.0);
+    let rotated = Quaternion::from_axis_angle(Vector3::new(2.0, 2.0, 1.0), 1.3).rotate(v);
+    assert_close(rotated.magnitude(), 13.0, 1e-12);
+}
+
+#[test]
+fn axis_angle_round_trip() {
+    let axis = Vector3::new(1.0, -2.0, 2.0).normalize();
+    for &angle in [0.1, 1.0, FRAC_PI_2, 3.0, PI].iter() {
+        let (actual_axis, actual_angle) = Quaternion::from_axis_angle(axis, angle).axis_angle();
+        assert_same_vector(actual_axis, axis);
+        assert_close(actual_angle, angle, 1e-12);
+    }
+
+    // A rotation by more than π is the rotation the other way about the opposite axis.
+    let (actual_axis, actual_angle) = Quaternion::from_axis_angle(axis, 1.5 * PI).axis_angle();
+    assert_same_vector(actual_axis, -axis);
+    assert_close(actual_angle, FRAC_PI_2, 1e-12);
+
+    let (identity_axis, identity_angle) = Quaternion::IDENTITY.axis_angle();
+    assert_eq!(identity_axis, Vector3::ZERO);
+    assert_close(identity_angle, 0.0, 1e-12);
+}
+
+#[test]
+fn from_to_takes_one_direction_onto_another() {
+    let cases = [
+        (X, Y),
+        (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-3.0, 0.5, 2.0)),
+        (Z, Z),
+        (Y, Y * 5.0),
+    ];
+    for &(from, to) in cases.iter() {
+        let rotation = Quaternion::from_to(from, to);
+        assert_same_vector(rotation.rotate(from.normalize()), to.normalize());
+        assert_close(rotation.axis_angle().1, from.angle_between(to), 1e-12);
+    }
+    assert_same_rotation(Quaternion::from_to(Z, Z), Quaternion::IDENTITY);
+}
+
+#[test]
+fn from_to_opposite_directions() {
+    for &from in [X, Y, Z, -X, Vector3::new(1.0, 1.0, -1.0)].iter() {
+        let rotation = Quaternion::from_to(from, -from);
+        assert_close(rotation.magnitude(), 1.0, 1e-12);
+        assert_same_vector(rotation.rotate(from.normalize()), -from.normalize());
+        assert_close(rotation.axis_angle().1, PI, 1e-12);
+    }
+}
+
+#[test]
+fn angle_between_vectors_and_rotations() {
+    assert_close(X.angle_between(Y), FRAC_PI_2, 1e-12);
+    assert_close(X.angle_between(-X), PI, 1e-12);
+    assert_close(X.angle_between(X * 3.0), 0.0, 1e-12);
+    assert_close(
+        X.angle_between(Vector3::new(1.0, 1.0, 0.0)),
+        PI / 4.0,
+        1e-12,
+    );
+    assert_close(X.angle_between(Vector3::new(1.0, 1e-9, 0.0)), 1e-9, 1e-12);
+
+    let a = Quaternion::from_axis_angle(Z, 0.3);
+    let b = Quaternion::from_axis_angle(Z, 1.2);
+    assert_close(a.angle_between(b), 0.9, 1e-12);
+    assert_close(b.angle_between(a), 0.9, 1e-12);
+    assert_close(a.angle_between(a), 0.0, 1e-12);
+    // q and -q are the same rotation.
+    let negated = Quaternion::new(-b.x, -b.y, -b.z, -b.w);
+    assert_close(a.angle_between(negated), 0.9, 1e-12);
+}
+
+#[test]
+fn slerp_interpolates_along_the_shortest_path() {
+    let a = Quaternion::from_axis_angle(X, 0.2);
+    let b = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 1.0), 2.5);
+    assert_same_rotation(a.slerp(b, 0.0), a);
+    assert_same_rotation(a.slerp(b, 1.0), b);
+
+    // Half way is half the angle from each end.
+    let middle = a.slerp(b, 0.5);
+    assert_close(a.angle_between(middle), a.angle_between(b) / 2.0, 1e-12);
+    assert_close(middle.angle_between(b), a.angle_between(b) / 2.0, 1e-12);
+
+    // With the other sign of `b`, it still takes the short way round.
+    let negated = Quaternion::new(-b.x, -b.y, -b.z, -b.w);
+    assert_same_rotation(a.slerp(negated, 0.5), middle);
+    assert_same_rotation(a.slerp(negated, 1.0), b);
+
+    // Nearly identical rotations are interpolated without dividing by zero.
+    let c = Quaternion::from_axis_angle(X, 0.2 + 1e-10);
+    assert_close(a.slerp(c, 0.5).magnitude(), 1.0, 1e-12);
+    assert_same_rotation(a.slerp(c, 1.0), c);
+}
+
+#[test]
+fn inverse_undoes_the_rotation() {
+    let q = Quaternion::from_axis_angle(Vector3::new(3.0, 1.0, -2.0), 2.1);
+    let v = Vector3::new(0.5, -7.0, 2.0);
+    assert_same_vector(q.inverse().rotate(q.rotate(v)), v);
+    assert_same_rotation(q * q.inverse(), Quaternion::IDENTITY);
+    assert_same_rotation(q.conjugate(), q.inverse());
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_conversions() {
+    let v = Vector3::new(1.0, -2.0, 3.5);
+    let converted: nalgebra::Vector3<f64> = v.into();
+    assert_eq!((converted.x, converted.y, converted.z), (1.0, -2.0, 3.5));
+    assert_eq!(Vector3::from(converted), v);
+
+    let q = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7);
+    let converted: nalgebra::Quaternion<f64> = q.into();
+    assert_eq!(
+        (converted.i, converted.j, converted.k, converted.w),
+        (q.x, q.y, q.z, q.w)
+    );
+    assert_eq!(Quaternion::from(converted), q);
+
+    let unit: nalgebra::UnitQuaternion<f64> = q.into();
+    assert_same_vector(
+        Vector3::from(unit * nalgebra::Vector3::from(v)),
+        q.rotate(v),
+    );
+    assert_same_rotation(Quaternion::from(unit), q);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_conversions() {
+    let v = Vector3::new(1.0, -2.0, 3.5);
+    let converted: glam::DVec3 = v.into();
+    assert_eq!(converted, glam::DVec3::new(1.0, -2.0, 3.5));
+    assert_eq!(Vector3::from(converted), v);
+
+    let q = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7);
+    let converted: glam::DQuat = q.into();
+    assert_eq!(converted, glam::DQuat::from_xyzw(q.x, q.y, q.z, q.w));
+    assert_eq!(Quaternion::from(converted), q);
+    assert_same_vector(Vector3::from(converted * glam::DVec3::from(v)), q.rotate(v));
+}
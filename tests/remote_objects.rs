@@ -0,0 +1,46 @@
+//! Checks the equality and ordering of remote objects.
+mod common;
+
+use krpc_bindings::spacecenter::Part;
+use krpc_bindings::RemoteObject;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn objects_are_compared_by_id() {
+    let connection = &common::connection();
+    let part = |id| Part::new(connection, id);
+    assert_eq!(part(3), part(3));
+    assert_ne!(part(3), part(4));
+
+    assert_eq!(hash(&part(3)), hash(&part(3)));
+}
+
+#[test]
+fn objects_are_ordered_by_id() {
+    let connection = &common::connection();
+    let mut parts: Vec<Part> = [12, 5, 40, 5, 7]
+        .iter()
+        .map(|&id| Part::new(connection, id))
+        .collect();
+    parts.sort();
+    parts.dedup();
+    let ids: Vec<u64> = parts.iter().map(|part| part.id()).collect();
+    assert_eq!(ids, vec![5, 7, 12, 40]);
+}
+
+#[test]
+fn objects_from_different_connections_differ() {
+    let (first, second) = (&common::connection(), &common::connection());
+    let (a, b) = (Part::new(first, 3), Part::new(second, 3));
+    assert_ne!(a, b);
+    assert_ne!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert!(Part::new(second, 2) < a);
+}
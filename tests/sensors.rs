@@ -0,0 +1,90 @@
+//! Checks the decoding of the images and point clouds sent by the DockingCamera and LiDAR
+//! services.
+mod common;
+
+use krpc_bindings::codec::{Bytes, CodecError, Decode, Encode};
+use krpc_bindings::dockingcamera::Frame;
+use krpc_bindings::lidar::PointCloud;
+use krpc_bindings::Vector3;
+
+/// A 2x2 RGB image, with red, green, blue and white pixels.
+const RGB_PNG: [u8; 75] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00, 0x00, 0xfd, 0xd4, 0x9a,
+    0x73, 0x00, 0x00, 0x00, 0x12, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8, 0xcf, 0xc0, 0xc0,
+    0x00, 0xc2, 0x0c, 0xff, 0x81, 0x00, 0x00, 0x1f, 0xee, 0x05, 0xfb, 0x0b, 0xd9, 0x68, 0x8b, 0x00,
+    0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+/// A 2x1 grayscale image, with black and light gray pixels.
+const GRAY_PNG: [u8; 68] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0xd1, 0x49, 0x20,
+    0x56, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x60, 0x38, 0x01, 0x00,
+    0x00, 0xcb, 0x00, 0xc9, 0x69, 0xc8, 0xc3, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44,
+    0xae, 0x42, 0x60, 0x82,
+];
+
+#[test]
+fn rgb_frame() {
+    let frame = Frame::from_png(&RGB_PNG).unwrap();
+    assert_eq!((frame.width(), frame.height()), (2, 2));
+    assert_eq!(frame.pixel(0, 0), Some([255, 0, 0, 255]));
+    assert_eq!(frame.pixel(1, 0), Some([0, 255, 0, 255]));
+    assert_eq!(frame.pixel(0, 1), Some([0, 0, 255, 255]));
+    assert_eq!(frame.pixel(1, 1), Some([255, 255, 255, 255]));
+    assert_eq!(frame.pixel(2, 0), None);
+    assert_eq!(frame.pixels().len(), 2 * 2 * 4);
+}
+
+#[test]
+fn grayscale_frame_is_expanded_to_rgba() {
+    let frame = Frame::from_png(&GRAY_PNG).unwrap();
+    assert_eq!((frame.width(), frame.height()), (2, 1));
+    assert_eq!(frame.into_pixels(), vec![0, 0, 0, 255, 200, 200, 200, 255]);
+}
+
+#[test]
+fn frame_is_decoded_from_bytes() {
+    let connection = &common::connection();
+    let bytes = Bytes(RGB_PNG.to_vec()).encode().unwrap();
+    assert_eq!(
+        Frame::decode(&bytes, connection).unwrap(),
+        Frame::from_png(&RGB_PNG).unwrap()
+    );
+
+    for bad in [&RGB_PNG[..40], &b"not a png"[..]].iter() {
+        let error = Frame::from_png(bad).unwrap_err();
+        match error.downcast::<CodecError>() {
+            Ok(CodecError::Malformed { .. }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn point_cloud_is_split_into_points() {
+    let connection = &common::connection();
+    let coordinates = vec![1.0, 2.0, 3.0, -4.0, 5.5, 0.0];
+    let cloud = PointCloud::decode(&coordinates.encode().unwrap(), connection).unwrap();
+    assert_eq!(
+        cloud.into_vec(),
+        vec![Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 5.5, 0.0)]
+    );
+
+    let empty = PointCloud::decode(&Vec::<f64>::new().encode().unwrap(), connection).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn partial_point_is_an_error() {
+    let connection = &common::connection();
+    for len in [1, 2, 4, 7].iter() {
+        let coordinates = vec![1.0f64; *len];
+        let error = PointCloud::decode(&coordinates.encode().unwrap(), connection).unwrap_err();
+        match error.downcast::<CodecError>() {
+            Ok(CodecError::Malformed { .. }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
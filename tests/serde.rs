@@ -0,0 +1,112 @@
+//! Checks the `serde` representations of remote enums, remote objects, protocol messages and
+//! the types the server returns. Run with `--features serde`.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use krpc_bindings::client::schema::{Procedure, Service};
+use krpc_bindings::client::{Services, Status};
+use krpc_bindings::codec::{Bytes, Encode};
+use krpc_bindings::krpc::{Client, KRPC};
+use krpc_bindings::spacecenter::{SASMode, Vessel, VesselSituation};
+use krpc_bindings::RemoteObject;
+
+use serde_json::json;
+
+#[test]
+fn remote_enums_by_name() {
+    let json = serde_json::to_value(VesselSituation::Orbiting).unwrap();
+    assert_eq!(json, json!("Orbiting"));
+    let situation: VesselSituation = serde_json::from_value(json).unwrap();
+    assert_eq!(situation, VesselSituation::Orbiting);
+
+    let modes = vec![SASMode::Prograde, SASMode::StabilityAssist];
+    let json = serde_json::to_string(&modes).unwrap();
+    assert_eq!(json, r#"["Prograde","StabilityAssist"]"#);
+    assert_eq!(serde_json::from_str::<Vec<SASMode>>(&json).unwrap(), modes);
+
+    assert!(serde_json::from_value::<VesselSituation>(json!("Hovering")).is_err());
+    assert!(serde_json::from_value::<VesselSituation>(json!(1)).is_err());
+}
+
+#[test]
+fn remote_objects_as_type_and_id() {
+    let connection = &common::connection();
+    let vessel = Vessel::new(connection, 42);
+    assert_eq!(
+        serde_json::to_value(&vessel).unwrap(),
+        json!({"type": "SpaceCenter.Vessel", "id": 42})
+    );
+    assert_eq!(
+        serde_json::to_value(vec![Some(vessel), None]).unwrap(),
+        json!([{"type": "SpaceCenter.Vessel", "id": 42}, null])
+    );
+}
+
+#[test]
+fn status_round_trip() {
+    let mut status = Status::new();
+    status.set_version("0.5.4".to_string());
+    status.set_bytes_read(1024);
+    status.set_rpc_rate(12.5);
+    status.set_adaptive_rate_control(true);
+
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["version"], json!("0.5.4"));
+    assert_eq!(json["bytes_read"], json!(1024));
+    assert_eq!(json["rpc_rate"], json!(12.5));
+    assert_eq!(serde_json::from_value::<Status>(json).unwrap(), status);
+}
+
+#[test]
+fn services_round_trip() {
+    let mut procedure = Procedure::new();
+    procedure.set_name("get_UT".to_string());
+    let mut service = Service::new();
+    service.set_name("SpaceCenter".to_string());
+    service.mut_procedures().push(procedure);
+    let mut services = Services::new();
+    services.mut_services().push(service);
+
+    let json = serde_json::to_value(&services).unwrap();
+    assert_eq!(json["services"][0]["name"], json!("SpaceCenter"));
+    assert_eq!(
+        json["services"][0]["procedures"][0]["name"],
+        json!("get_UT")
+    );
+    assert_eq!(serde_json::from_value::<Services>(json).unwrap(), services);
+}
+
+#[test]
+fn clients_round_trip() {
+    let clients: Vec<Client> = vec![
+        (
+            Bytes(vec![0x01, 0xab, 0xff]),
+            "telemetry".to_string(),
+            "127.0.0.1".to_string(),
+        ),
+        (Bytes(Vec::new()), String::new(), "10.0.0.2".to_string()),
+    ];
+    let encoded = (clients.encode().unwrap(), clients[0].0.encode().unwrap());
+    let (connection, _updates) = common::serving(move |call| match call.get_procedure() {
+        "get_Clients" => encoded.0.clone(),
+        "GetClientID" => encoded.1.clone(),
+        procedure => panic!("unexpected call to {}", procedure),
+    });
+    let krpc = KRPC::new(&connection);
+
+    let json = serde_json::to_value(krpc.clients().unwrap()).unwrap();
+    assert_eq!(
+        json,
+        json!([["01abff", "telemetry", "127.0.0.1"], ["", "", "10.0.0.2"],])
+    );
+    assert_eq!(
+        serde_json::from_value::<Vec<Client>>(json).unwrap(),
+        clients
+    );
+
+    let json = serde_json::to_value(krpc.client_id().unwrap()).unwrap();
+    assert_eq!(json, json!("01abff"));
+    assert_eq!(serde_json::from_value::<Bytes>(json).unwrap(), clients[0].0);
+    assert!(serde_json::from_value::<Bytes>(json!("not hex")).is_err());
+}
@@ -0,0 +1,198 @@
+//! Checks staging tables worked out for small vessels, built by hand.
+mod common;
+
+use common::assert_close;
+use krpc_bindings::guidance::STANDARD_GRAVITY;
+use krpc_bindings::spacecenter::ResourceFlowMode;
+use krpc_bindings::staging::{EngineModel, PartModel, ResourceModel, Stage, VesselModel};
+
+use std::collections::BTreeMap;
+
+const KERBIN_GRAVITY: f64 = 9.81;
+
+fn resources() -> BTreeMap<String, ResourceModel> {
+    let resource = |density, flow_mode| ResourceModel { density, flow_mode };
+    vec![
+        ("LiquidFuel", resource(5.0, ResourceFlowMode::Adjacent)),
+        ("Oxidizer", resource(5.0, ResourceFlowMode::Adjacent)),
+        ("SolidFuel", resource(7.5, ResourceFlowMode::None)),
+    ]
+    .into_iter()
+    .map(|(name, resource)| (name.to_string(), resource))
+    .collect()
+}
+
+fn part(decouple_stage: i32, dry_mass: f64, draws_from: Vec<usize>) -> PartModel {
+    PartModel {
+        decouple_stage,
+        dry_mass,
+        resources: BTreeMap::new(),
+        crossfeed: true,
+        draws_from,
+        engine: None,
+    }
+}
+
+/// A tank holding `units` of liquid fuel and oxidizer in the usual mix.
+fn tank(decouple_stage: i32, units: f64, draws_from: Vec<usize>) -> PartModel {
+    PartModel {
+        resources: vec![
+            ("LiquidFuel".to_string(), units * 0.45),
+            ("Oxidizer".to_string(), units * 0.55),
+        ]
+        .into_iter()
+        .collect(),
+        ..part(decouple_stage, units / 8.0, draws_from)
+    }
+}
+
+fn engine(
+    decouple_stage: i32,
+    stage: i32,
+    vacuum_thrust: f64,
+    draws_from: Vec<usize>,
+) -> PartModel {
+    PartModel {
+        engine: Some(EngineModel {
+            stage,
+            vacuum_thrust,
+            vacuum_isp: 320.0,
+            sea_level_isp: 280.0,
+            propellants: vec![
+                ("LiquidFuel".to_string(), 0.9),
+                ("Oxidizer".to_string(), 1.1),
+            ]
+            .into_iter()
+            .collect(),
+        }),
+        ..part(decouple_stage, 1500.0, draws_from)
+    }
+}
+
+/// A core stage, with radial boosters on decouplers, which can have fuel lines into the core.
+fn boosted(fuel_lines: bool) -> VesselModel {
+    let mut parts = vec![
+        tank(-1, 4000.0, vec![1, 2]),
+        engine(-1, 1, 200_000.0, vec![0]),
+        PartModel {
+            crossfeed: false,
+            ..part(0, 50.0, vec![0, 3])
+        },
+        tank(0, 2000.0, vec![2, 4]),
+        engine(0, 1, 200_000.0, vec![3]),
+    ];
+    if fuel_lines {
+        parts[0].draws_from.push(3);
+    }
+    VesselModel {
+        parts,
+        resources: resources(),
+        current_stage: 2,
+    }
+}
+
+#[test]
+fn single_stage() {
+    let vessel = VesselModel {
+        parts: vec![
+            part(-1, 800.0, vec![1]),
+            tank(-1, 4000.0, vec![0, 2]),
+            engine(-1, 0, 200_000.0, vec![1]),
+        ],
+        resources: resources(),
+        current_stage: 1,
+    };
+    let stages = vessel.stages(KERBIN_GRAVITY);
+    assert_eq!(stages.len(), 2);
+
+    // Nothing burns until the engine is activated.
+    assert_eq!(stages[0].stage, 1);
+    assert_eq!(stages[0].vacuum_delta_v, 0.0);
+    assert_eq!(stages[0].wet_mass, stages[0].dry_mass);
+
+    let stage = stages[1];
+    let (wet, dry) = (800.0 + 500.0 + 20_000.0 + 1500.0, 800.0 + 500.0 + 1500.0);
+    assert_close(stage.wet_mass, wet, 1e-9);
+    assert_close(stage.dry_mass, dry, 1e-9);
+    assert_close(
+        stage.vacuum_delta_v,
+        320.0 * STANDARD_GRAVITY * (wet / dry).ln(),
+        1e-9,
+    );
+    assert_close(
+        stage.sea_level_delta_v,
+        280.0 * STANDARD_GRAVITY * (wet / dry).ln(),
+        1e-9,
+    );
+    assert_close(
+        stage.burn_time,
+        (wet - dry) / (200_000.0 / (320.0 * STANDARD_GRAVITY)),
+        1e-9,
+    );
+    assert_close(stage.vacuum_twr, 200_000.0 / (wet * KERBIN_GRAVITY), 1e-9);
+    assert_close(
+        stage.sea_level_twr,
+        200_000.0 * 280.0 / 320.0 / (wet * KERBIN_GRAVITY),
+        1e-9,
+    );
+}
+
+#[test]
+fn boosters_are_dropped_when_dry() {
+    let stages = boosted(false).stages(KERBIN_GRAVITY);
+    assert_eq!(stages.len(), 3);
+    let flow = 200_000.0 / (320.0 * STANDARD_GRAVITY);
+
+    // The boosters burn their own fuel, and the core stage its own, until the boosters are dry.
+    let boosters = stages[1];
+    assert_close(boosters.burn_time, 2000.0 * 5.0 / flow, 1e-9);
+    assert_close(
+        boosters.wet_mass - boosters.dry_mass,
+        2.0 * 2000.0 * 5.0,
+        1e-9,
+    );
+
+    // The core stage carries on with what is left, without the boosters.
+    let core = stages[2];
+    let booster_mass = 50.0 + 250.0 + 1500.0;
+    assert_close(core.wet_mass, boosters.dry_mass - booster_mass, 1e-9);
+    assert_close(core.dry_mass, 500.0 + 1500.0, 1e-9);
+    assert_close(core.burn_time, (4000.0 - 2000.0) * 5.0 / flow, 1e-9);
+}
+
+#[test]
+fn fuel_lines_drain_the_boosters_first() {
+    let stages = boosted(true).stages(KERBIN_GRAVITY);
+    let flow = 200_000.0 / (320.0 * STANDARD_GRAVITY);
+
+    // Both engines burn the boosters' fuel, so the core stage is full when they are dropped.
+    let boosters = stages[1];
+    assert_close(boosters.burn_time, 2000.0 * 5.0 / (2.0 * flow), 1e-9);
+    let core = stages[2];
+    assert_close(core.wet_mass, 500.0 + 20_000.0 + 1500.0, 1e-9);
+    assert_close(core.burn_time, 4000.0 * 5.0 / flow, 1e-9);
+
+    let total = |stages: &[Stage]| -> f64 { stages.iter().map(|stage| stage.vacuum_delta_v).sum() };
+    assert!(total(&stages) > total(&boosted(false).stages(KERBIN_GRAVITY)));
+}
+
+#[test]
+fn solid_fuel_does_not_flow() {
+    let mut booster = engine(-1, 0, 100_000.0, vec![1]);
+    booster.resources.insert("SolidFuel".to_string(), 100.0);
+    if let Some(engine) = booster.engine.as_mut() {
+        engine.propellants = vec![("SolidFuel".to_string(), 1.0)].into_iter().collect();
+    }
+    let mut nose = part(-1, 100.0, vec![0]);
+    nose.resources.insert("SolidFuel".to_string(), 400.0);
+
+    let stages = VesselModel {
+        parts: vec![booster, nose],
+        resources: resources(),
+        current_stage: 0,
+    }
+    .stages(KERBIN_GRAVITY);
+    assert_eq!(stages.len(), 1);
+    assert_close(stages[0].wet_mass - stages[0].dry_mass, 100.0 * 7.5, 1e-9);
+    assert_close(stages[0].dry_mass, 1500.0 + 100.0 + 400.0 * 7.5, 1e-9);
+}
@@ -0,0 +1,87 @@
+//! Checks waiting for stream updates, against a stand-in for the server that sends them.
+mod common;
+
+use krpc_bindings::client::{Stream, StreamError};
+use krpc_bindings::codec::Encode;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+/// A waker that reports each wake on a channel.
+struct Notify(Sender<()>);
+
+impl Wake for Notify {
+    fn wake(self: Arc<Self>) {
+        self.0.send(()).unwrap();
+    }
+}
+
+fn waker() -> (Waker, Receiver<()>) {
+    let (sender, woken) = mpsc::channel();
+    (Waker::from(Arc::new(Notify(sender))), woken)
+}
+
+fn poll<F: Future + Unpin>(future: &mut F, waker: &Waker) -> Poll<F::Output> {
+    Pin::new(future).poll(&mut Context::from_waker(waker))
+}
+
+#[test]
+fn wait_async_is_woken_by_the_next_update() {
+    let (connection, mut updates) =
+        common::serving(|call| panic!("unexpected call to {}", call.get_procedure()));
+    let stream: Stream<f64> = connection.add_stream("SpaceCenter", "get_UT", &[]).unwrap();
+    stream.start().unwrap();
+
+    let (waker, woken) = waker();
+    let mut wait = stream.wait_async();
+    assert!(poll(&mut wait, &waker).is_pending());
+    assert!(poll(&mut wait, &waker).is_pending());
+    assert!(woken.try_recv().is_err());
+
+    common::send_stream_update(&mut updates, stream.id(), 12.5.encode().unwrap());
+    woken.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(matches!(poll(&mut wait, &waker), Poll::Ready(Ok(()))));
+    assert_eq!(stream.value().unwrap(), 12.5);
+    // Polling twice with the same waker registers it once.
+    assert!(woken.recv_timeout(Duration::from_millis(100)).is_err());
+
+    // A new wait waits for the update after the current one.
+    let mut wait = stream.wait_async();
+    assert!(poll(&mut wait, &waker).is_pending());
+    common::send_stream_update(&mut updates, stream.id(), 13.0.encode().unwrap());
+    woken.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(matches!(poll(&mut wait, &waker), Poll::Ready(Ok(()))));
+    assert_eq!(stream.value().unwrap(), 13.0);
+}
+
+#[test]
+fn wait_async_needs_a_started_stream() {
+    let (connection, _updates) =
+        common::serving(|call| panic!("unexpected call to {}", call.get_procedure()));
+    let mut stream: Stream<f64> = connection.add_stream("SpaceCenter", "get_UT", &[]).unwrap();
+    let (waker, _woken) = waker();
+
+    let error = match poll(&mut stream.wait_async(), &waker) {
+        Poll::Ready(Err(error)) => error,
+        _ => panic!("expected an error"),
+    };
+    assert!(matches!(
+        error.downcast::<StreamError>().unwrap(),
+        StreamError::NotStarted
+    ));
+
+    stream.start().unwrap();
+    stream.remove().unwrap();
+    let error = match poll(&mut stream.wait_async(), &waker) {
+        Poll::Ready(Err(error)) => error,
+        _ => panic!("expected an error"),
+    };
+    assert!(matches!(
+        error.downcast::<StreamError>().unwrap(),
+        StreamError::Removed
+    ));
+}